@@ -81,7 +81,8 @@ export class ContractService {
   async getProposal(proposalId: number): Promise<ProposalData | null> {
     try {
       // Buscar todos os dados da proposta em paralelo
-      const [titleResponse, descriptionResponse, creatorResponse, voteCountResponse, deadlineResponse, activeResponse] = await Promise.all([
+      const [spaceResponse, titleResponse, descriptionResponse, creatorResponse, voteCountResponse, deadlineResponse, activeResponse] = await Promise.all([
+        this.queryContract('get_proposal_space', [new U64Value(proposalId)]),
         this.queryContract('get_proposal_title', [new U64Value(proposalId)]),
         this.queryContract('get_proposal_description', [new U64Value(proposalId)]),
         this.queryContract('get_proposal_creator', [new U64Value(proposalId)]),
@@ -90,6 +91,11 @@ export class ContractService {
         this.queryContract('is_proposal_active', [new U64Value(proposalId)])
       ]);
 
+      // Propostas de espaços compartilham a numeração global, mas não aparecem aqui
+      if ((this.decodeNumber(spaceResponse) || 0) !== 0) {
+        return null;
+      }
+
       // Decodificar resultados
       const title = this.decodeString(titleResponse) || `Proposta ${proposalId + 1}`;
       const description = this.decodeString(descriptionResponse) || 'Sem descrição';
@@ -144,7 +150,7 @@ export class ContractService {
   async getContractStats() {
     try {
       const [proposalsResponse, votesResponse] = await Promise.all([
-        this.queryContract('get_total_proposals', []),
+        this.queryContract('get_default_proposal_count', []),
         this.queryContract('get_total_votes', [])
       ]);
 
@@ -0,0 +1,322 @@
+// chainballotx_factory.rs
+// Localização: contracts/chainballotx_factory.rs
+
+#![no_std]
+
+use multiversx_sc::imports::*;
+
+use chainballotx::chainballotx_proxy::ChainBallotXProxy;
+
+pub mod chainballotx_factory_proxy;
+
+// Modelo de propriedade das instâncias:
+// - a administração da votação (owner no storage da instância) fica com o `admin` da organização;
+// - a propriedade no protocolo (quem pode fazer upgrade do código) começa com a fábrica,
+//   que mantém as instâncias atualizadas via `upgrade_instance`;
+// - o admin pode chamar `release_instance` para tornar a instância dona de si mesma.
+//   A partir daí só propostas de upgrade aprovadas na própria instância trocam o código,
+//   e a fábrica deixa de poder atualizá-la.
+
+// Constantes do contrato
+const MAX_PAGE_SIZE: usize = 50;
+const MAX_ORGANIZATION_ID_LENGTH: usize = 64;
+const UPGRADE_GAS: u64 = 20_000_000; // gás repassado ao upgrade da instância
+
+#[multiversx_sc::contract]
+pub trait ChainBallotXFactory {
+    #[init]
+    fn init(&self, template_address: ManagedAddress) {
+        let caller = self.blockchain().get_caller();
+        self.owner().set(&caller);
+
+        self.require_valid_template(&template_address);
+        self.template_address().set(&template_address);
+        self.factory_initialized_event(&caller, &template_address);
+    }
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    // ============= FUNCÕES ADMINISTRATIVAS =============
+
+    // Novas implantações e upgrades em lote passam a usar o novo template
+    #[endpoint]
+    fn set_template(&self, template_address: ManagedAddress) {
+        self.require_owner();
+        self.require_valid_template(&template_address);
+
+        self.template_address().set(&template_address);
+        self.template_changed_event(&template_address);
+    }
+
+    #[endpoint]
+    fn transfer_ownership(&self, new_owner: ManagedAddress) {
+        self.require_owner();
+        require!(!new_owner.is_zero(), "Invalid new owner address");
+
+        let old_owner = self.owner().get();
+        self.owner().set(&new_owner);
+        self.ownership_transferred_event(&old_owner, &new_owner);
+    }
+
+    // ============= INSTÂNCIAS =============
+
+    // A fábrica continua dona da instância no protocolo até o admin liberá-la;
+    // a administração da votação passa para `admin`
+    #[endpoint]
+    fn deploy_from_source(
+        &self,
+        organization_id: ManagedBuffer,
+        admin: ManagedAddress,
+    ) -> ManagedAddress {
+        self.require_owner();
+        require!(
+            !organization_id.is_empty() && organization_id.len() <= MAX_ORGANIZATION_ID_LENGTH,
+            "Invalid organization id"
+        );
+        require!(!admin.is_zero(), "Invalid admin address");
+
+        let template = self.template_address().get();
+        let instance = self
+            .tx()
+            .typed(ChainBallotXProxy)
+            .init()
+            .from_source(template)
+            .code_metadata(self.instance_code_metadata())
+            .returns(ReturnsNewManagedAddress)
+            .sync_call();
+
+        self.tx()
+            .to(&instance)
+            .typed(ChainBallotXProxy)
+            .transfer_ownership(&admin)
+            .sync_call();
+
+        self.instances().push(&instance);
+        self.organizations().insert(organization_id.clone());
+        self.organization_instances(&organization_id).push(&instance);
+        self.instance_organization(&instance).set(&organization_id);
+
+        self.instance_deployed_event(&organization_id, &instance, &admin);
+        instance
+    }
+
+    // Atualiza a instância na posição `index` (base 0) com o template atual.
+    // O upgrade a partir de outro contrato é uma chamada assíncrona que encerra a
+    // execução, então cabe um por transação: o lote é percorrido fora da cadeia,
+    // posição por posição, com a ajuda de `get_instances`.
+    #[endpoint]
+    fn upgrade_instance(&self, index: usize) {
+        self.require_owner();
+
+        let instances = self.instances();
+        require!(index < instances.len(), "Invalid instance index");
+        let instance = instances.get(index + 1);
+        require!(
+            !self.instance_released(&instance).get(),
+            "Instance already released"
+        );
+
+        let template = self.template_address().get();
+        self.instance_upgrade_requested_event(&instance, &template);
+        self.tx()
+            .to(&instance)
+            .gas(UPGRADE_GAS)
+            .typed(ChainBallotXProxy)
+            .upgrade()
+            .from_source(template)
+            .code_metadata(self.instance_code_metadata())
+            .upgrade_async_call_and_exit();
+    }
+
+    // Passa a propriedade da instância no protocolo para ela mesma, habilitando
+    // os upgrades por governança; não há como devolvê-la à fábrica depois
+    #[endpoint]
+    fn release_instance(&self, instance: ManagedAddress) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            !self.instance_organization(&instance).is_empty(),
+            "Instance does not exist"
+        );
+        require!(
+            !self.instance_released(&instance).get(),
+            "Instance already released"
+        );
+
+        let admin = self
+            .tx()
+            .to(&instance)
+            .typed(ChainBallotXProxy)
+            .get_owner()
+            .returns(ReturnsResult)
+            .sync_call();
+        require!(caller == admin, "Only instance admin can release");
+
+        self.tx()
+            .to(&instance)
+            .typed(UserBuiltinProxy)
+            .change_owner_address(&instance)
+            .sync_call();
+
+        self.instance_released(&instance).set(true);
+        self.instance_released_event(&instance, &caller);
+    }
+
+    // ============= VIEWS =============
+
+    #[view]
+    fn get_owner(&self) -> ManagedAddress {
+        self.owner().get()
+    }
+
+    #[view]
+    fn get_template(&self) -> ManagedAddress {
+        self.template_address().get()
+    }
+
+    #[view]
+    fn get_instance_count(&self) -> usize {
+        self.instances().len()
+    }
+
+    // `from` é a posição (base 0) na ordem de implantação
+    #[view]
+    fn get_instances(&self, from: usize, count: usize) -> MultiValueEncoded<ManagedAddress> {
+        let instances = self.instances();
+        let page_size = core::cmp::min(count, MAX_PAGE_SIZE);
+        let end = core::cmp::min(from.saturating_add(page_size), instances.len());
+
+        let mut result = MultiValueEncoded::new();
+        for index in from..end {
+            result.push(instances.get(index + 1));
+        }
+        result
+    }
+
+    #[view]
+    fn get_organization_count(&self) -> usize {
+        self.organizations().len()
+    }
+
+    #[view]
+    fn get_organizations(&self, from: usize, count: usize) -> MultiValueEncoded<ManagedBuffer> {
+        let organizations = self.organizations();
+        let page_size = core::cmp::min(count, MAX_PAGE_SIZE);
+        let end = core::cmp::min(from.saturating_add(page_size), organizations.len());
+
+        let mut result = MultiValueEncoded::new();
+        for index in from..end {
+            result.push(organizations.get_by_index(index + 1));
+        }
+        result
+    }
+
+    #[view]
+    fn get_organization_instances(
+        &self,
+        organization_id: ManagedBuffer,
+    ) -> MultiValueEncoded<ManagedAddress> {
+        let mut result = MultiValueEncoded::new();
+        for instance in self.organization_instances(&organization_id).iter() {
+            result.push(instance);
+        }
+        result
+    }
+
+    #[view]
+    fn get_instance_organization(&self, instance: ManagedAddress) -> OptionalValue<ManagedBuffer> {
+        let mapper = self.instance_organization(&instance);
+        if mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(mapper.get())
+        }
+    }
+
+    #[view]
+    fn is_instance_released(&self, instance: ManagedAddress) -> bool {
+        self.instance_released(&instance).get()
+    }
+
+    // ============= FUNCÕES AUXILIARES =============
+
+    fn require_owner(&self) {
+        let caller = self.blockchain().get_caller();
+        require!(caller == self.owner().get(), "Only owner can call this function");
+    }
+
+    fn require_valid_template(&self, template_address: &ManagedAddress) {
+        require!(
+            self.blockchain().is_smart_contract(template_address),
+            "Template must be a smart contract"
+        );
+    }
+
+    fn instance_code_metadata(&self) -> CodeMetadata {
+        CodeMetadata::UPGRADEABLE | CodeMetadata::READABLE
+    }
+
+    // ============= EVENTOS =============
+
+    #[event("factoryInitialized")]
+    fn factory_initialized_event(
+        &self,
+        #[indexed] owner: &ManagedAddress,
+        #[indexed] template_address: &ManagedAddress,
+    );
+
+    #[event("templateChanged")]
+    fn template_changed_event(&self, #[indexed] template_address: &ManagedAddress);
+
+    #[event("ownershipTransferred")]
+    fn ownership_transferred_event(
+        &self,
+        #[indexed] old_owner: &ManagedAddress,
+        #[indexed] new_owner: &ManagedAddress,
+    );
+
+    #[event("instanceDeployed")]
+    fn instance_deployed_event(
+        &self,
+        #[indexed] organization_id: &ManagedBuffer,
+        #[indexed] instance: &ManagedAddress,
+        #[indexed] admin: &ManagedAddress,
+    );
+
+    #[event("instanceUpgradeRequested")]
+    fn instance_upgrade_requested_event(
+        &self,
+        #[indexed] instance: &ManagedAddress,
+        #[indexed] template_address: &ManagedAddress,
+    );
+
+    #[event("instanceReleased")]
+    fn instance_released_event(
+        &self,
+        #[indexed] instance: &ManagedAddress,
+        #[indexed] admin: &ManagedAddress,
+    );
+
+    // ============= STORAGE MAPPERS =============
+
+    #[storage_mapper("owner")]
+    fn owner(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("templateAddress")]
+    fn template_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("instances")]
+    fn instances(&self) -> VecMapper<ManagedAddress>;
+
+    #[storage_mapper("organizations")]
+    fn organizations(&self) -> UnorderedSetMapper<ManagedBuffer>;
+
+    #[storage_mapper("organizationInstances")]
+    fn organization_instances(&self, organization_id: &ManagedBuffer) -> VecMapper<ManagedAddress>;
+
+    #[storage_mapper("instanceOrganization")]
+    fn instance_organization(&self, instance: &ManagedAddress) -> SingleValueMapper<ManagedBuffer>;
+
+    #[storage_mapper("instanceReleased")]
+    fn instance_released(&self, instance: &ManagedAddress) -> SingleValueMapper<bool>;
+}
@@ -0,0 +1,336 @@
+use multiversx_sc::types::{ManagedAddress, ManagedBuffer};
+use multiversx_sc_scenario::*;
+use multiversx_sc_scenario::scenario_model::*;
+use multiversx_sc_scenario::imports::{
+    SetStateStep,             // Para definir o estado inicial do mundo
+    FilePath,                 // Para especificar o caminho do arquivo WASM
+    ExpectValue,              // Para verificar valores esperados
+    ExpectMessage,            // Para verificar mensagens de erro
+    ReturnsResult,            // Para ler o retorno de views
+    MultiValueEncoded,        // Para argumentos variáveis (opções)
+    StaticApi,                // API usada pelos tipos gerenciados nos testes
+    TestAddress,              // Para criar endereços de teste
+    TestSCAddress,            // Para criar endereços de smart contract de teste
+};
+use chainballotx::chainballotx_proxy::ChainBallotXProxy;
+use chainballotx_factory::*;
+use chainballotx_factory_proxy::ChainBallotXFactoryProxy;
+
+mod chainballotx_factory_tests {
+    use super::*;
+
+    const FACTORY_WASM_PATH: &str = "output/chainballotx-factory.wasm";
+    const TEMPLATE_WASM_PATH: &str = "../chainballotx/output/chainballotx.wasm";
+
+    // Endereços de teste constantes
+    const OWNER_ADDRESS: TestAddress = TestAddress::new("owner");
+    const ADMIN_A_ADDRESS: TestAddress = TestAddress::new("admin_a");
+    const ADMIN_B_ADDRESS: TestAddress = TestAddress::new("admin_b");
+    const TEMPLATE_ADDRESS: TestSCAddress = TestSCAddress::new("template");
+    const FACTORY_ADDRESS: TestSCAddress = TestSCAddress::new("factory");
+    const INSTANCE_A_ADDRESS: TestSCAddress = TestSCAddress::new("instance_a");
+    const INSTANCE_B_ADDRESS: TestSCAddress = TestSCAddress::new("instance_b");
+    const TEMPLATE_V2_ADDRESS: TestSCAddress = TestSCAddress::new("template_v2");
+
+    #[test]
+    fn test_deploy_instances_per_organization() {
+        let mut world = ScenarioWorld::new();
+        world.register_contract("file:output/chainballotx-factory.wasm", chainballotx_factory::ContractBuilder);
+        world.register_contract("file:../chainballotx/output/chainballotx.wasm", chainballotx::ContractBuilder);
+
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .put_account(OWNER_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(ADMIN_A_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(ADMIN_B_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .new_address(OWNER_ADDRESS, 1, TEMPLATE_ADDRESS)
+                    .new_address(OWNER_ADDRESS, 2, FACTORY_ADDRESS)
+                    .new_address(FACTORY_ADDRESS, 0, INSTANCE_A_ADDRESS)
+                    .new_address(FACTORY_ADDRESS, 1, INSTANCE_B_ADDRESS)
+            );
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .init()
+            .code(FilePath(TEMPLATE_WASM_PATH))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .typed(ChainBallotXFactoryProxy)
+            .init(TEMPLATE_ADDRESS.to_address())
+            .code(FilePath(FACTORY_WASM_PATH))
+            .run();
+
+        world
+            .tx()
+            .from(ADMIN_A_ADDRESS)
+            .to(FACTORY_ADDRESS)
+            .typed(ChainBallotXFactoryProxy)
+            .deploy_from_source(ManagedBuffer::new_from_bytes(b"org-a"), ADMIN_A_ADDRESS.to_address())
+            .with_result(ExpectMessage("Only owner can call this function"))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(FACTORY_ADDRESS)
+            .typed(ChainBallotXFactoryProxy)
+            .deploy_from_source(ManagedBuffer::new(), ADMIN_A_ADDRESS.to_address())
+            .with_result(ExpectMessage("Invalid organization id"))
+            .run();
+
+        for (organization_id, admin, instance) in [
+            (&b"org-a"[..], ADMIN_A_ADDRESS, INSTANCE_A_ADDRESS),
+            (&b"org-b"[..], ADMIN_B_ADDRESS, INSTANCE_B_ADDRESS),
+        ] {
+            world
+                .tx()
+                .from(OWNER_ADDRESS)
+                .to(FACTORY_ADDRESS)
+                .typed(ChainBallotXFactoryProxy)
+                .deploy_from_source(ManagedBuffer::new_from_bytes(organization_id), admin.to_address())
+                .returns(ExpectValue(instance.to_address()))
+                .run();
+
+            // Cada organização administra a própria instância
+            world
+                .query()
+                .to(instance)
+                .typed(ChainBallotXProxy)
+                .get_owner()
+                .returns(ExpectValue(admin.to_address()))
+                .run();
+        }
+
+        world
+            .query()
+            .to(FACTORY_ADDRESS)
+            .typed(ChainBallotXFactoryProxy)
+            .get_instance_count()
+            .returns(ExpectValue(2usize))
+            .run();
+
+        let instances = world
+            .query()
+            .to(FACTORY_ADDRESS)
+            .typed(ChainBallotXFactoryProxy)
+            .get_organization_instances(ManagedBuffer::new_from_bytes(b"org-b"))
+            .returns(ReturnsResult)
+            .run();
+        let instances: Vec<ManagedAddress<StaticApi>> = instances.into_iter().collect();
+        assert_eq!(instances, vec![INSTANCE_B_ADDRESS.to_managed_address()]);
+
+        let organization = world
+            .query()
+            .to(FACTORY_ADDRESS)
+            .typed(ChainBallotXFactoryProxy)
+            .get_instance_organization(INSTANCE_A_ADDRESS.to_address())
+            .returns(ReturnsResult)
+            .run();
+        assert_eq!(
+            organization.into_option(),
+            Some(ManagedBuffer::new_from_bytes(b"org-a"))
+        );
+
+        // As instâncias são independentes entre si
+        let no_options = MultiValueEncoded::<StaticApi, ManagedBuffer<StaticApi>>::new();
+        world
+            .tx()
+            .from(ADMIN_A_ADDRESS)
+            .to(INSTANCE_A_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .create_proposal(
+                ManagedBuffer::new_from_bytes(b"Proposta da Org A"),
+                ManagedBuffer::new_from_bytes(b"Instancia propria"),
+                3600u64,
+                no_options,
+            )
+            .run();
+
+        world
+            .query()
+            .to(INSTANCE_A_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_total_proposals()
+            .returns(ExpectValue(1u64))
+            .run();
+
+        world
+            .query()
+            .to(INSTANCE_B_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_total_proposals()
+            .returns(ExpectValue(0u64))
+            .run();
+    }
+
+    #[test]
+    fn test_upgrade_instances() {
+        let mut world = ScenarioWorld::new();
+        world.register_contract("file:output/chainballotx-factory.wasm", chainballotx_factory::ContractBuilder);
+        world.register_contract("file:../chainballotx/output/chainballotx.wasm", chainballotx::ContractBuilder);
+        // Segunda versão do template, identificada pelo código
+        world.register_contract("str:chainballotx-v2", chainballotx::ContractBuilder);
+
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .put_account(OWNER_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(ADMIN_A_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(ADMIN_B_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(
+                        TEMPLATE_V2_ADDRESS,
+                        Account::new().nonce(1).code("str:chainballotx-v2").owner(OWNER_ADDRESS)
+                    )
+                    .new_address(OWNER_ADDRESS, 1, TEMPLATE_ADDRESS)
+                    .new_address(OWNER_ADDRESS, 2, FACTORY_ADDRESS)
+                    .new_address(FACTORY_ADDRESS, 0, INSTANCE_A_ADDRESS)
+                    .new_address(FACTORY_ADDRESS, 1, INSTANCE_B_ADDRESS)
+            );
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .init()
+            .code(FilePath(TEMPLATE_WASM_PATH))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .typed(ChainBallotXFactoryProxy)
+            .init(TEMPLATE_ADDRESS.to_address())
+            .code(FilePath(FACTORY_WASM_PATH))
+            .run();
+
+        for (organization_id, admin) in [(&b"org-a"[..], ADMIN_A_ADDRESS), (&b"org-b"[..], ADMIN_B_ADDRESS)] {
+            world
+                .tx()
+                .from(OWNER_ADDRESS)
+                .to(FACTORY_ADDRESS)
+                .typed(ChainBallotXFactoryProxy)
+                .deploy_from_source(ManagedBuffer::new_from_bytes(organization_id), admin.to_address())
+                .run();
+        }
+
+        // A fábrica é dona das instâncias no protocolo; a votação é do admin
+        world
+            .check_state_step(
+                CheckStateStep::new()
+                    .put_account(INSTANCE_A_ADDRESS, CheckAccount::new().owner(FACTORY_ADDRESS))
+                    .put_account(INSTANCE_B_ADDRESS, CheckAccount::new().owner(FACTORY_ADDRESS))
+            );
+
+        world
+            .tx()
+            .from(ADMIN_B_ADDRESS)
+            .to(FACTORY_ADDRESS)
+            .typed(ChainBallotXFactoryProxy)
+            .release_instance(INSTANCE_A_ADDRESS.to_address())
+            .with_result(ExpectMessage("Only instance admin can release"))
+            .run();
+
+        // A instância A passa a ser dona de si mesma e só muda de código por governança
+        world
+            .tx()
+            .from(ADMIN_A_ADDRESS)
+            .to(FACTORY_ADDRESS)
+            .typed(ChainBallotXFactoryProxy)
+            .release_instance(INSTANCE_A_ADDRESS.to_address())
+            .run();
+
+        world
+            .tx()
+            .from(ADMIN_A_ADDRESS)
+            .to(FACTORY_ADDRESS)
+            .typed(ChainBallotXFactoryProxy)
+            .release_instance(INSTANCE_A_ADDRESS.to_address())
+            .with_result(ExpectMessage("Instance already released"))
+            .run();
+
+        world
+            .query()
+            .to(FACTORY_ADDRESS)
+            .typed(ChainBallotXFactoryProxy)
+            .is_instance_released(INSTANCE_A_ADDRESS.to_address())
+            .returns(ExpectValue(true))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(FACTORY_ADDRESS)
+            .typed(ChainBallotXFactoryProxy)
+            .set_template(ADMIN_A_ADDRESS.to_address())
+            .with_result(ExpectMessage("Template must be a smart contract"))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(FACTORY_ADDRESS)
+            .typed(ChainBallotXFactoryProxy)
+            .set_template(TEMPLATE_V2_ADDRESS.to_address())
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(FACTORY_ADDRESS)
+            .typed(ChainBallotXFactoryProxy)
+            .upgrade_instance(2usize)
+            .with_result(ExpectMessage("Invalid instance index"))
+            .run();
+
+        // Um upgrade por transação; a instância liberada fica de fora
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(FACTORY_ADDRESS)
+            .typed(ChainBallotXFactoryProxy)
+            .upgrade_instance(0usize)
+            .with_result(ExpectMessage("Instance already released"))
+            .run();
+
+        world
+            .tx()
+            .from(ADMIN_B_ADDRESS)
+            .to(FACTORY_ADDRESS)
+            .typed(ChainBallotXFactoryProxy)
+            .upgrade_instance(1usize)
+            .with_result(ExpectMessage("Only owner can call this function"))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(FACTORY_ADDRESS)
+            .typed(ChainBallotXFactoryProxy)
+            .upgrade_instance(1usize)
+            .run();
+
+        // Só a instância ainda controlada pela fábrica recebe o novo código
+        world
+            .check_state_step(
+                CheckStateStep::new()
+                    .put_account(
+                        INSTANCE_A_ADDRESS,
+                        CheckAccount::new()
+                            .owner(INSTANCE_A_ADDRESS)
+                            .code("file:../chainballotx/output/chainballotx.wasm")
+                    )
+                    .put_account(
+                        INSTANCE_B_ADDRESS,
+                        CheckAccount::new()
+                            .owner(FACTORY_ADDRESS)
+                            .code("str:chainballotx-v2")
+                    )
+            );
+    }
+}
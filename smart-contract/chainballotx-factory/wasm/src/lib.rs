@@ -0,0 +1,40 @@
+// Code generated by the multiversx-sc build system. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+// Init:                                 1
+// Upgrade:                              1
+// Endpoints:                           14
+// Async Callback (empty):               1
+// Total number of exported functions:  17
+
+#![no_std]
+
+multiversx_sc_wasm_adapter::allocator!();
+multiversx_sc_wasm_adapter::panic_handler!();
+
+multiversx_sc_wasm_adapter::endpoints! {
+    chainballotx_factory
+    (
+        init => init
+        upgrade => upgrade
+        set_template => set_template
+        transfer_ownership => transfer_ownership
+        deploy_from_source => deploy_from_source
+        upgrade_instance => upgrade_instance
+        release_instance => release_instance
+        get_owner => get_owner
+        get_template => get_template
+        get_instance_count => get_instance_count
+        get_instances => get_instances
+        get_organization_count => get_organization_count
+        get_organizations => get_organizations
+        get_organization_instances => get_organization_instances
+        get_instance_organization => get_instance_organization
+        is_instance_released => is_instance_released
+    )
+}
+
+multiversx_sc_wasm_adapter::async_callback_empty! {}
@@ -6,12 +6,47 @@
 use multiversx_sc::imports::*;
 
 pub mod chainballotx_proxy;
+pub mod types;
+
+use types::{
+    AntiSnipingConfig, BallotType, BountyRule, Category, CodeUpgrade, ConvictionConfig, ConvictionRequest, HashAlgorithm,
+    NftGate, OperationPause, PauseScope, PauseState, PredictionMarket, PredictionPosition, Proposal, ProposalDocument, ProposalHeader, ProposalOutcome, ProposalStatus, RankedCountState, ScoreRange,
+    Space, SpaceConfig, TreasurySpend, VestingSchedule, VestingStream, VoteEscrowConfig, VoteEscrowLock, VoteRecord,
+    VotingPowerMode,
+};
 
 // Constantes do contrato
 const MIN_DURATION: u64 = 3600; // 1 hora
 const MAX_PROPOSALS_PER_USER: usize = 10;
 const MAX_TITLE_LENGTH: usize = 100;
 const MAX_DESCRIPTION_LENGTH: usize = 1000;
+const MAX_PAGE_SIZE: usize = 50;
+const MAX_OPTIONS: usize = 20;
+const MAX_OPTION_LENGTH: usize = 50;
+const MAX_CONTENT_HASH_LENGTH: usize = 100;
+const MAX_CATEGORIES: usize = 50;
+const MAX_CATEGORY_NAME_LENGTH: usize = 50;
+const MAX_TAGS: usize = 5;
+const MAX_TAG_LENGTH: usize = 32;
+const MAX_BPS: u64 = 10_000;
+const UNCATEGORIZED: usize = 0;
+const MAX_AMENDMENTS_PER_PROPOSAL: usize = 10;
+const MAX_REASON_LENGTH: usize = 200;
+const ANTI_SNIPING_REASON: &[u8] = b"anti-sniping";
+const MIN_GAS_FOR_COUNT_STEP: u64 = 1_000_000;
+const MAX_SCORE: usize = 100;
+const VOTE_WEIGHT: u64 = 1_000_000_000; // peso de uma cédula inteira na apuração STV
+const CONVICTION_SCALE: u64 = 1_000_000_000; // precisão do decaimento de convicção
+const VOTING_REWARD_CLAIM_PERIOD: u64 = 30 * 24 * 3600; // 30 dias após o prazo de votação
+const LEGACY_STORAGE_VERSION: u32 = 1; // um mapper por campo da proposta
+const CURRENT_STORAGE_VERSION: u32 = 2; // campos fixos agrupados em ProposalHeader
+const MIN_GAS_FOR_MIGRATION_STEP: u64 = 2_000_000;
+const CODE_UPGRADE_DELAY: u64 = 2 * 24 * 3600; // 2 dias entre o fim da votação e o upgrade
+const CODE_HASH_LENGTH: usize = 32;
+const MAX_SPACES: usize = 100;
+const MAX_SPACE_NAME_LENGTH: usize = 50;
+const MAX_SPACE_VOTERS_PER_CALL: usize = 100;
+const INDEFINITE_PAUSE: u64 = u64::MAX;
 
 #[multiversx_sc::contract]
 pub trait ChainBallotX {
@@ -22,9 +57,19 @@ pub trait ChainBallotX {
         self.is_paused().set(false);
         self.total_proposals().set(0u64);
         self.total_votes().set(0u64);
+        self.storage_version().set(CURRENT_STORAGE_VERSION);
         self.contract_initialized_event(&caller);
     }
 
+    // A migração de dados não roda aqui: o dono chama migrate_storage em lotes
+    #[upgrade]
+    fn upgrade(&self) {
+        if self.storage_version().is_empty() {
+            self.storage_version().set(LEGACY_STORAGE_VERSION);
+        }
+        self.contract_upgraded_event(self.storage_version().get());
+    }
+
     // ============= FUNCÕES ADMINISTRATIVAS =============
 
     #[endpoint]
@@ -41,6 +86,48 @@ pub trait ChainBallotX {
         self.contract_unpaused_event();
     }
 
+    #[endpoint]
+    fn add_pauser(&self, pauser: ManagedAddress) {
+        self.require_owner();
+        require!(!pauser.is_zero(), "Invalid pauser address");
+        require!(self.pausers().insert(pauser.clone()), "Already a pauser");
+        self.pauser_added_event(&pauser);
+    }
+
+    #[endpoint]
+    fn remove_pauser(&self, pauser: ManagedAddress) {
+        self.require_owner();
+        require!(self.pausers().swap_remove(&pauser), "Not a pauser");
+        self.pauser_removed_event(&pauser);
+    }
+
+    // Sem `unpause_at` a operação fica pausada até unpause_operation
+    #[endpoint]
+    fn pause_operation(&self, scope: PauseScope, unpause_at: OptionalValue<u64>) {
+        self.require_pauser();
+
+        let paused_until = match unpause_at {
+            OptionalValue::Some(timestamp) => {
+                require!(
+                    timestamp > self.blockchain().get_block_timestamp() && timestamp != INDEFINITE_PAUSE,
+                    "Invalid unpause timestamp"
+                );
+                timestamp
+            },
+            OptionalValue::None => INDEFINITE_PAUSE,
+        };
+
+        self.operation_paused_until(scope).set(paused_until);
+        self.operation_paused_event(scope, paused_until);
+    }
+
+    #[endpoint]
+    fn unpause_operation(&self, scope: PauseScope) {
+        self.require_pauser();
+        self.operation_paused_until(scope).clear();
+        self.operation_unpaused_event(scope);
+    }
+
     #[endpoint]
     fn transfer_ownership(&self, new_owner: ManagedAddress) {
         self.require_owner();
@@ -51,227 +138,4684 @@ pub trait ChainBallotX {
         self.ownership_transferred_event(&old_owner, &new_owner);
     }
 
-    // ============= FUNCÕES DE PROPOSTA =============
+    #[endpoint]
+    fn add_category(
+        &self,
+        name: ManagedBuffer,
+        quorum: u64,
+        threshold_bps: u64,
+        min_duration: u64,
+    ) -> usize {
+        self.require_owner();
+        require!(!name.is_empty(), "Category name cannot be empty");
+        require!(name.len() <= MAX_CATEGORY_NAME_LENGTH, "Category name too long");
+        require!(self.categories().len() < MAX_CATEGORIES, "Maximum categories exceeded");
+        self.require_valid_category_rules(threshold_bps, min_duration);
+
+        // VecMapper começa em 1, então o id 0 fica reservado para "sem categoria"
+        let category_id = self.categories().len() + 1;
+        self.categories().push(&Category {
+            id: category_id,
+            name,
+            quorum,
+            threshold_bps,
+            min_duration,
+        });
 
+        self.category_added_event(category_id, quorum, threshold_bps, min_duration);
+        category_id
+    }
+
+    // Alterações valem apenas para propostas classificadas depois da atualização
     #[endpoint]
-    fn create_proposal(
+    fn update_category(
         &self,
-        title: ManagedBuffer,
-        description: ManagedBuffer,
-        duration: u64,
+        category_id: usize,
+        quorum: u64,
+        threshold_bps: u64,
+        min_duration: u64,
     ) {
-        self.require_not_paused();
-        let caller = self.blockchain().get_caller();
+        self.require_owner();
+        self.require_category_exists(category_id);
+        self.require_valid_category_rules(threshold_bps, min_duration);
 
-        // Validações básicas
-        require!(!title.is_empty(), "Title cannot be empty");
-        require!(!description.is_empty(), "Description cannot be empty");
-        require!(title.len() <= MAX_TITLE_LENGTH, "Title too long");
-        require!(description.len() <= MAX_DESCRIPTION_LENGTH, "Description too long");
-        require!(duration >= MIN_DURATION, "Duration too short");
+        let mut category = self.categories().get(category_id);
+        category.quorum = quorum;
+        category.threshold_bps = threshold_bps;
+        category.min_duration = min_duration;
+        self.categories().set(category_id, &category);
 
-        // Limitar propostas por usuário
-        let user_proposals = self.user_proposal_count(&caller).get();
+        self.category_updated_event(category_id, quorum, threshold_bps, min_duration);
+    }
+
+    // `window` igual a zero desativa a regra
+    #[endpoint]
+    fn set_anti_sniping(&self, window: u64, extension: u64, max_extensions: usize) {
+        self.require_owner();
         require!(
-            user_proposals < MAX_PROPOSALS_PER_USER,
-            "Maximum proposals per user exceeded"
+            window == 0 || (extension > 0 && max_extensions > 0),
+            "Invalid anti-sniping config"
         );
 
-        // Criar a proposta
-        let proposal_id = self.total_proposals().get();
-        let current_time = self.blockchain().get_block_timestamp();
-        
-        self.proposal_title(proposal_id).set(&title);
-        self.proposal_description(proposal_id).set(&description);
-        self.proposal_creator(proposal_id).set(&caller);
-        self.proposal_deadline(proposal_id).set(current_time + duration);
-        self.proposal_vote_count(proposal_id).set(0u64);
-        self.proposal_active(proposal_id).set(true);
-
-        // Atualizar contadores
-        self.total_proposals().update(|x| *x += 1);
-        self.user_proposal_count(&caller).update(|x| *x += 1);
-
-        self.proposal_created_event(proposal_id, &caller);
+        self.anti_sniping_config().set(AntiSnipingConfig {
+            window,
+            extension,
+            max_extensions,
+        });
     }
 
     #[endpoint]
-    fn vote(&self, proposal_id: u64) {
-        self.require_not_paused();
-        
-        let caller = self.blockchain().get_caller();
-        let current_time = self.blockchain().get_block_timestamp();
+    fn extend_deadline(&self, proposal_id: u64, extension: u64, reason: ManagedBuffer) {
+        self.require_owner();
 
-        // Validações
         require!(proposal_id < self.total_proposals().get(), "Proposal does not exist");
-        require!(self.proposal_active(proposal_id).get(), "Proposal is not active");
-        require!(current_time <= self.proposal_deadline(proposal_id).get(), "Voting period ended");
+        self.require_default_space(proposal_id);
         require!(
-            !self.has_voted(proposal_id, &caller).get(),
-            "Already voted on this proposal"
+            self.compute_proposal_status(proposal_id) == ProposalStatus::Active,
+            "Proposal is not active"
+        );
+        require!(
+            self.conviction_request(proposal_id).is_empty(),
+            "Conviction proposals have no deadline"
         );
+        require!(extension > 0, "Invalid extension");
+        require!(!reason.is_empty(), "Reason cannot be empty");
+        require!(reason.len() <= MAX_REASON_LENGTH, "Reason too long");
 
-        // Registrar voto
-        self.proposal_vote_count(proposal_id).update(|x| *x += 1);
-        self.has_voted(proposal_id, &caller).set(true);
-        self.total_votes().update(|x| *x += 1);
+        let caller = self.blockchain().get_caller();
+        self.extend_proposal_family(proposal_id, extension, &caller, &reason);
+    }
 
-        self.vote_cast_event(proposal_id, &caller);
+    // Valor pago em EGLD a quem finalizar uma proposta; zero desativa
+    #[endpoint]
+    fn set_finalization_bounty(&self, amount: BigUint) {
+        self.require_owner();
+        self.finalization_bounty().set(&amount);
     }
 
+    // Token usado em stakes de governança; não pode ser trocado depois de definido
     #[endpoint]
-    fn cancel_proposal(&self, proposal_id: u64) {
-        self.require_not_paused();
-        let caller = self.blockchain().get_caller();
-        
-        // Validações
-        require!(proposal_id < self.total_proposals().get(), "Proposal does not exist");
-        require!(self.proposal_active(proposal_id).get(), "Proposal is not active");
+    fn set_governance_token(&self, token_id: TokenIdentifier) {
+        self.require_owner();
+        require!(token_id.is_valid_esdt_identifier(), "Invalid token identifier");
         require!(
-            caller == self.proposal_creator(proposal_id).get() || caller == self.owner().get(),
-            "Only creator or owner can cancel proposal"
+            self.governance_token().is_empty(),
+            "Governance token already set"
         );
 
-        // Cancelar
-        self.proposal_active(proposal_id).set(false);
-        self.proposal_cancelled_event(proposal_id, &caller);
+        self.governance_token().set(&token_id);
     }
 
-    // ============= VIEWS SIMPLES =============
-
-    #[view]
-    fn get_proposal_title(&self, proposal_id: u64) -> ManagedBuffer {
+    #[endpoint]
+    fn set_conviction_config(
+        &self,
+        decay_bps: u64,
+        period: u64,
+        max_ratio_bps: u64,
+        weight_bps: u64,
+    ) {
+        self.require_owner();
+        require!(decay_bps < MAX_BPS, "Invalid decay");
+        require!(period > 0, "Invalid period");
         require!(
-            proposal_id < self.total_proposals().get(),
-            "Proposal does not exist"
+            max_ratio_bps > 0 && max_ratio_bps <= MAX_BPS,
+            "Invalid max ratio"
         );
-        self.proposal_title(proposal_id).get()
+        require!(weight_bps > 0, "Invalid weight");
+
+        self.conviction_config().set(ConvictionConfig {
+            decay_bps,
+            period,
+            max_ratio_bps,
+            weight_bps,
+        });
     }
 
-    #[view]
-    fn get_proposal_description(&self, proposal_id: u64) -> ManagedBuffer {
-        require!(
-            proposal_id < self.total_proposals().get(),
-            "Proposal does not exist"
-        );
-        self.proposal_description(proposal_id).get()
+    #[endpoint]
+    fn set_vote_escrow_config(&self, max_lock_duration: u64, power_unit: BigUint) {
+        self.require_owner();
+        require!(max_lock_duration > 0, "Invalid lock duration");
+        require!(power_unit > 0u32, "Invalid power unit");
+
+        self.vote_escrow_config().set(VoteEscrowConfig {
+            max_lock_duration,
+            power_unit,
+        });
     }
 
-    #[view]
-    fn get_proposal_creator(&self, proposal_id: u64) -> ManagedAddress {
-        require!(
-            proposal_id < self.total_proposals().get(),
-            "Proposal does not exist"
-        );
-        self.proposal_creator(proposal_id).get()
+    // Regra aplicada às recompensas anexadas a novas propostas
+    #[endpoint]
+    fn set_default_bounty_rule(&self, rule: BountyRule) {
+        self.require_owner();
+        self.default_bounty_rule().set(rule);
     }
 
-    #[view]
-    fn get_proposal_vote_count(&self, proposal_id: u64) -> u64 {
-        require!(
-            proposal_id < self.total_proposals().get(),
-            "Proposal does not exist"
-        );
-        self.proposal_vote_count(proposal_id).get()
+    // Mínimos exigidos de propostas com ação de governança (gasto, stream, upgrade);
+    // aplicados na publicação, acima das regras da categoria
+    #[endpoint]
+    fn set_governance_action_rules(&self, quorum: u64, threshold_bps: u64) {
+        self.require_owner();
+        require!(quorum > 0, "Invalid quorum");
+        require!(threshold_bps > 0 && threshold_bps <= MAX_BPS, "Invalid threshold");
+
+        self.governance_action_quorum().set(quorum);
+        self.governance_action_threshold_bps().set(threshold_bps);
     }
 
-    #[view]
-    fn get_proposal_deadline(&self, proposal_id: u64) -> u64 {
-        require!(
-            proposal_id < self.total_proposals().get(),
-            "Proposal does not exist"
-        );
-        self.proposal_deadline(proposal_id).get()
+    // Taxa sobre o lado perdedor dos mercados de previsão criados a partir daqui
+    #[endpoint]
+    fn set_prediction_fee(&self, fee_bps: u64) {
+        self.require_owner();
+        require!(fee_bps <= MAX_BPS, "Invalid fee");
+        self.prediction_fee_bps().set(fee_bps);
     }
 
-    #[view]
-    fn is_proposal_active(&self, proposal_id: u64) -> bool {
+    // Converte até `batch_size` propostas para o layout atual enquanto houver gás;
+    // retorna true ao concluir. Propostas ainda não convertidas continuam legíveis
+    // pelo layout antigo.
+    #[endpoint]
+    fn migrate_storage(&self, batch_size: u64) -> bool {
+        self.require_owner();
         require!(
-            proposal_id < self.total_proposals().get(),
-            "Proposal does not exist"
+            self.storage_version().get() < CURRENT_STORAGE_VERSION,
+            "Storage already migrated"
         );
-        self.proposal_active(proposal_id).get()
+        require!(batch_size > 0, "Batch size must be greater than zero");
+
+        let total = self.total_proposals().get();
+        let mut cursor = self.migration_cursor().get();
+        let batch_end = core::cmp::min(total, cursor.saturating_add(batch_size));
+        while cursor < batch_end && self.blockchain().get_gas_left() >= MIN_GAS_FOR_MIGRATION_STEP {
+            if self.proposal_header(cursor).is_empty() {
+                self.migrate_legacy_proposal(cursor);
+            }
+            cursor += 1;
+        }
+        self.migration_cursor().set(cursor);
+
+        if cursor < total {
+            self.storage_migration_progress_event(cursor, total);
+            return false;
+        }
+
+        self.storage_version().set(CURRENT_STORAGE_VERSION);
+        self.storage_migrated_event(CURRENT_STORAGE_VERSION);
+        true
     }
 
-    #[view]
-    fn get_total_proposals(&self) -> u64 {
-        self.total_proposals().get()
+    // ============= FUNCÕES DE PROPOSTA =============
+
+    // Pagamento anexado (EGLD ou um ESDT) vira recompensa do criador
+    #[payable("*")]
+    #[endpoint]
+    fn create_proposal(
+        &self,
+        title: ManagedBuffer,
+        description: ManagedBuffer,
+        duration: u64,
+        options: MultiValueEncoded<ManagedBuffer>,
+    ) {
+        self.require_not_paused(PauseScope::Creation);
+        require!(duration >= MIN_DURATION, "Duration too short");
+
+        let proposal_id = self.register_proposal(title, description, duration, options.to_vec());
+        self.attach_creator_bounty(proposal_id);
+        self.open_voting(proposal_id);
     }
 
-    #[view]
-    fn get_total_votes(&self) -> u64 {
-        self.total_votes().get()
+    // Rascunhos podem ser editados pelo criador até a publicação
+    #[payable("*")]
+    #[endpoint]
+    fn create_draft(
+        &self,
+        title: ManagedBuffer,
+        description: ManagedBuffer,
+        duration: u64,
+        options: MultiValueEncoded<ManagedBuffer>,
+    ) -> u64 {
+        self.require_not_paused(PauseScope::Creation);
+        require!(duration >= MIN_DURATION, "Duration too short");
+
+        let proposal_id = self.register_proposal(title, description, duration, options.to_vec());
+        self.attach_creator_bounty(proposal_id);
+        self.proposal_draft(proposal_id).set(true);
+        proposal_id
     }
 
-    #[view]
-    fn has_user_voted_on_proposal(&self, proposal_id: u64, user_address: ManagedAddress) -> bool {
+    // A emenda herda opções, categoria, modo de voto e prazo da proposta original
+    #[endpoint]
+    fn create_amendment(
+        &self,
+        parent_id: u64,
+        title: ManagedBuffer,
+        description: ManagedBuffer,
+    ) -> u64 {
+        self.require_not_paused(PauseScope::Creation);
+
+        require!(parent_id < self.total_proposals().get(), "Proposal does not exist");
+        self.require_default_space(parent_id);
         require!(
-            proposal_id < self.total_proposals().get(),
-            "Proposal does not exist"
+            self.compute_proposal_status(parent_id) == ProposalStatus::Active,
+            "Proposal is not active"
+        );
+        require!(
+            self.proposal_parent(parent_id).is_empty(),
+            "Cannot amend an amendment"
+        );
+        require!(
+            self.load_ballot_type(parent_id) == BallotType::SingleChoice,
+            "Amendments require single-choice ballots"
+        );
+        require!(
+            self.load_seats(parent_id) == 1,
+            "Amendments require single-seat proposals"
+        );
+        require!(
+            self.conviction_request(parent_id).is_empty(),
+            "Cannot amend a conviction proposal"
+        );
+        require!(
+            self.treasury_spend(parent_id).is_empty(),
+            "Cannot amend a treasury spend proposal"
+        );
+        require!(
+            self.stream_cancellation(parent_id).is_empty(),
+            "Cannot amend a stream cancellation proposal"
+        );
+        require!(
+            self.code_upgrade(parent_id).is_empty(),
+            "Cannot amend a code upgrade proposal"
+        );
+        require!(
+            self.proposal_space(parent_id).is_empty(),
+            "Cannot amend a space proposal"
+        );
+        require!(
+            self.prediction_market(parent_id).is_empty(),
+            "Cannot amend a prediction market proposal"
+        );
+        require!(
+            self.proposal_amendments(parent_id).len() < MAX_AMENDMENTS_PER_PROPOSAL,
+            "Maximum amendments exceeded"
         );
-        self.has_voted(proposal_id, &user_address).get()
-    }
 
-    #[view]
-    fn is_contract_paused(&self) -> bool {
-        self.is_paused().get()
-    }
+        let current_time = self.blockchain().get_block_timestamp();
+        let duration = self.proposal_deadline(parent_id).get() - current_time;
+        let options = self.proposal_options(parent_id).iter().collect();
 
-    #[view]
-    fn get_owner(&self) -> ManagedAddress {
-        self.owner().get()
+        let amendment_id = self.register_proposal(title, description, duration, options);
+        self.assign_category(
+            amendment_id,
+            self.proposal_category(parent_id).get(),
+            self.proposal_quorum(parent_id).get(),
+            self.proposal_threshold_bps(parent_id).get(),
+        );
+        self.proposal_voting_power(amendment_id)
+            .set(self.load_voting_power(parent_id));
+        if !self.nft_gate(parent_id).is_empty() {
+            self.nft_gate(amendment_id).set(self.nft_gate(parent_id).get());
+        }
+        self.proposal_parent(amendment_id).set(Some(parent_id));
+        self.proposal_amendments(parent_id).push(&amendment_id);
+        self.open_voting(amendment_id);
+
+        let caller = self.blockchain().get_caller();
+        self.amendment_created_event(parent_id, amendment_id, &caller);
+        amendment_id
     }
 
-    // ============= FUNCÕES AUXILIARES =============
+    #[endpoint]
+    fn edit_proposal(
+        &self,
+        proposal_id: u64,
+        title: ManagedBuffer,
+        description: ManagedBuffer,
+        options: MultiValueEncoded<ManagedBuffer>,
+    ) {
+        self.require_not_paused(PauseScope::Creation);
+        self.require_draft_owned_by_caller(proposal_id);
 
-    fn require_owner(&self) {
+        let options = options.to_vec();
+        self.require_valid_proposal_content(&title, &description, &options);
+
+        let mut header = self.load_proposal_header(proposal_id);
+        header.title = title;
+        header.description = description;
+        self.save_proposal_header(proposal_id, &header);
+        self.proposal_options(proposal_id).clear();
+        for option in options.iter() {
+            self.proposal_options(proposal_id).push(&*option);
+        }
+
+        let edit_count = self.proposal_edit_count(proposal_id).update(|x| {
+            *x += 1;
+            *x
+        });
         let caller = self.blockchain().get_caller();
-        require!(caller == self.owner().get(), "Only owner can call this function");
+        self.proposal_edited_event(proposal_id, &caller, edit_count);
     }
 
-    fn require_not_paused(&self) {
-        require!(!self.is_paused().get(), "Contract is paused");
+    // O tipo de cédula só pode ser escolhido enquanto a proposta é rascunho
+    #[endpoint]
+    fn set_ballot_type(&self, proposal_id: u64, ballot_type: BallotType) {
+        self.require_not_paused(PauseScope::Creation);
+        self.require_draft_owned_by_caller(proposal_id);
+
+        self.proposal_ballot_type(proposal_id).set(ballot_type);
+        self.ballot_type_set_event(proposal_id, ballot_type);
     }
 
-    // ============= EVENTOS =============
+    #[endpoint]
+    fn set_score_range(&self, proposal_id: u64, min_score: usize, max_score: usize) {
+        self.require_not_paused(PauseScope::Creation);
+        self.require_draft_owned_by_caller(proposal_id);
+        require!(
+            min_score < max_score && max_score <= MAX_SCORE,
+            "Invalid score range"
+        );
 
-    #[event("contractInitialized")]
-    fn contract_initialized_event(&self, #[indexed] owner: &ManagedAddress);
+        self.proposal_score_range(proposal_id).set(ScoreRange {
+            min: min_score,
+            max: max_score,
+        });
+    }
 
-    #[event("proposalCreated")]
-    fn proposal_created_event(
-        &self,
-        #[indexed] proposal_id: u64,
-        #[indexed] creator: &ManagedAddress,
-    );
+    // Com mais de uma vaga, cédulas ranqueadas são apuradas por STV e as demais por
+    // pluralidade (as N opções mais votadas)
+    #[endpoint]
+    fn set_proposal_seats(&self, proposal_id: u64, seats: usize) {
+        self.require_not_paused(PauseScope::Creation);
+        self.require_draft_owned_by_caller(proposal_id);
+        require!(seats >= 1, "Invalid number of seats");
 
-    #[event("voteCast")]
-    fn vote_cast_event(
-        &self,
-        #[indexed] proposal_id: u64,
-        #[indexed] voter: &ManagedAddress,
-    );
+        self.proposal_seats(proposal_id).set(seats);
+        self.proposal_seats_set_event(proposal_id, seats);
+    }
 
-    #[event("proposalCancelled")]
-    fn proposal_cancelled_event(
-        &self, 
-        #[indexed] proposal_id: u64, 
-        #[indexed] cancelled_by: &ManagedAddress
-    );
+    #[endpoint]
+    fn set_voting_power(&self, proposal_id: u64, mode: VotingPowerMode) {
+        self.require_not_paused(PauseScope::Creation);
+        self.require_draft_owned_by_caller(proposal_id);
+        if mode == VotingPowerMode::VoteEscrow {
+            require!(
+                !self.vote_escrow_config().is_empty(),
+                "Vote escrow not configured"
+            );
+        }
 
-    #[event("contractPaused")]
-    fn contract_paused_event(&self);
+        require!(mode != VotingPowerMode::Nft, "Use set_nft_gate for NFT voting");
 
-    #[event("contractUnpaused")]
-    fn contract_unpaused_event(&self);
+        self.proposal_voting_power(proposal_id).set(mode);
+        self.voting_power_set_event(proposal_id, mode);
+    }
 
-    #[event("ownershipTransferred")]
-    fn ownership_transferred_event(
+    // Um voto por NFT da coleção; cada nonce vota uma única vez por proposta
+    #[endpoint]
+    fn set_nft_gate(&self, proposal_id: u64, collection: TokenIdentifier, return_immediately: bool) {
+        self.require_not_paused(PauseScope::Creation);
+        self.require_draft_owned_by_caller(proposal_id);
+        require!(collection.is_valid_esdt_identifier(), "Invalid token identifier");
+
+        self.nft_gate(proposal_id).set(NftGate {
+            collection,
+            return_immediately,
+        });
+        self.proposal_voting_power(proposal_id).set(VotingPowerMode::Nft);
+        self.voting_power_set_event(proposal_id, VotingPowerMode::Nft);
+    }
+
+    // A opção 0 aprova o gasto; o saldo só é verificado na execução
+    #[endpoint]
+    fn set_treasury_spend(
+        &self,
+        proposal_id: u64,
+        recipient: ManagedAddress,
+        token_id: EgldOrEsdtTokenIdentifier,
+        token_nonce: u64,
+        amount: BigUint,
+    ) {
+        self.require_not_paused(PauseScope::Creation);
+        self.require_draft_owned_by_caller(proposal_id);
+        require!(token_id.is_valid(), "Invalid token identifier");
+        require!(token_nonce == 0, "Treasury only holds fungible tokens");
+        require!(amount > 0u32, "Amount must be greater than zero");
+        require!(
+            self.stream_cancellation(proposal_id).is_empty()
+                && self.code_upgrade(proposal_id).is_empty(),
+            "Proposal already has a governance action"
+        );
+
+        self.treasury_spend(proposal_id).set(TreasurySpend {
+            recipient,
+            token_id,
+            token_nonce,
+            amount,
+        });
+    }
+
+    // Com cronograma, o gasto aprovado vira um stream em vez de uma transferência única
+    #[endpoint]
+    fn set_spend_vesting(&self, proposal_id: u64, start: u64, cliff: u64, end: u64) {
+        self.require_not_paused(PauseScope::Creation);
+        self.require_draft_owned_by_caller(proposal_id);
+        require!(
+            !self.treasury_spend(proposal_id).is_empty(),
+            "Treasury spend not set"
+        );
+        require!(
+            start <= cliff && cliff <= end && start < end,
+            "Invalid vesting schedule"
+        );
+
+        self.spend_vesting(proposal_id).set(VestingSchedule { start, cliff, end });
+    }
+
+    #[endpoint]
+    fn set_stream_cancellation(&self, proposal_id: u64, stream_id: usize) {
+        self.require_not_paused(PauseScope::Creation);
+        self.require_draft_owned_by_caller(proposal_id);
+        self.require_open_stream(stream_id);
+        require!(
+            self.treasury_spend(proposal_id).is_empty()
+                && self.code_upgrade(proposal_id).is_empty(),
+            "Proposal already has a governance action"
+        );
+
+        self.stream_cancellation(proposal_id).set(stream_id);
+    }
+
+    // O código em si é enviado só na execução e conferido contra o hash votado
+    #[endpoint]
+    fn set_code_upgrade(
+        &self,
+        proposal_id: u64,
+        code_hash: ManagedBuffer,
+        code_metadata: CodeMetadata,
+    ) {
+        self.require_not_paused(PauseScope::Creation);
+        self.require_draft_owned_by_caller(proposal_id);
+        require!(code_hash.len() == CODE_HASH_LENGTH, "Invalid code hash");
+        require!(
+            self.treasury_spend(proposal_id).is_empty()
+                && self.stream_cancellation(proposal_id).is_empty(),
+            "Proposal already has a governance action"
+        );
+
+        self.code_upgrade(proposal_id).set(CodeUpgrade {
+            code_hash,
+            code_metadata,
+        });
+    }
+
+    #[endpoint]
+    fn set_creator_bounty_rule(&self, proposal_id: u64, rule: BountyRule) {
+        self.require_not_paused(PauseScope::Creation);
+        self.require_draft_owned_by_caller(proposal_id);
+        require!(
+            !self.creator_bounty(proposal_id).is_empty(),
+            "Proposal has no bounty"
+        );
+
+        self.creator_bounty_rule(proposal_id).set(rule);
+    }
+
+    #[endpoint]
+    fn set_prediction_market(&self, proposal_id: u64, token_id: EgldOrEsdtTokenIdentifier) {
+        self.require_not_paused(PauseScope::Creation);
+        self.require_draft_owned_by_caller(proposal_id);
+        require!(token_id.is_valid(), "Invalid token identifier");
+
+        self.prediction_market(proposal_id).set(PredictionMarket {
+            token_id,
+            fee_bps: self.prediction_fee_bps().get(),
+        });
+    }
+
+    #[endpoint]
+    fn publish_proposal(&self, proposal_id: u64) {
+        self.require_not_paused(PauseScope::Creation);
+        self.require_draft_owned_by_caller(proposal_id);
+        let ballot_type = self.load_ballot_type(proposal_id);
+        if ballot_type != BallotType::SingleChoice {
+            require!(
+                !self.proposal_options(proposal_id).is_empty(),
+                "Ballot type requires options"
+            );
+        }
+        if ballot_type == BallotType::Score {
+            require!(
+                !self.proposal_score_range(proposal_id).is_empty(),
+                "Score range not set"
+            );
+        }
+        let seats = self.load_seats(proposal_id);
+        require!(
+            seats == 1 || seats < self.proposal_options(proposal_id).len(),
+            "Seats must be fewer than options"
+        );
+        if self.load_voting_power(proposal_id) != VotingPowerMode::OnePerAddress {
+            require!(
+                ballot_type == BallotType::SingleChoice || ballot_type == BallotType::Approval,
+                "Weighted voting requires single-choice or approval ballots"
+            );
+        }
+        if self.has_governance_action(proposal_id) {
+            require!(
+                ballot_type == BallotType::SingleChoice && seats == 1,
+                "Governance actions require a single-choice ballot"
+            );
+            self.apply_governance_action_rules(proposal_id);
+        }
+        if self.load_creator_bounty_rule(proposal_id) == BountyRule::WinningVoters {
+            require!(
+                ballot_type == BallotType::SingleChoice && seats == 1,
+                "Winning-voter bounties require a single-choice ballot"
+            );
+        }
+        if !self.prediction_market(proposal_id).is_empty() {
+            require!(
+                ballot_type == BallotType::SingleChoice
+                    && seats == 1
+                    && !self.has_governance_action(proposal_id),
+                "Prediction markets require a non-binding single-choice poll"
+            );
+        }
+
+        self.proposal_draft(proposal_id).clear();
+        self.open_voting(proposal_id);
+        self.proposal_published_event(proposal_id, self.proposal_deadline(proposal_id).get());
+    }
+
+    // Escolha única: no máximo um índice (vazio vota na opção 0).
+    // Ranqueada: índices distintos em ordem de preferência.
+    // Aprovação: um único valor com um bit por opção aprovada.
+    // Nota: uma nota por opção, dentro do intervalo configurado.
+    // Propostas com NFT exigem os NFTs da coleção como pagamento.
+    #[payable("*")]
+    #[endpoint]
+    fn vote(&self, proposal_id: u64, ballot: MultiValueEncoded<usize>) {
+        self.require_not_paused(PauseScope::Voting);
+        require!(proposal_id < self.total_proposals().get(), "Proposal does not exist");
+        self.require_default_space(proposal_id);
+
+        self.cast_vote(proposal_id, ballot.to_vec());
+    }
+
+    fn cast_vote(&self, proposal_id: u64, ballot: ManagedVec<usize>) {
+        let caller = self.blockchain().get_caller();
+        let current_time = self.blockchain().get_block_timestamp();
+
+        // Validações
+        require!(self.proposal_active(proposal_id).get(), "Proposal is not active");
+        require!(!self.proposal_draft(proposal_id).get(), "Proposal is not published");
+        require!(current_time <= self.proposal_deadline(proposal_id).get(), "Voting period ended");
+        require!(
+            self.conviction_request(proposal_id).is_empty(),
+            "Conviction proposals use staking"
+        );
+        require!(
+            !self.has_voted(proposal_id, &caller).get(),
+            "Already voted on this proposal"
+        );
+
+        let weight = self.compute_vote_weight(proposal_id, &caller);
+        let (leader_before, _) = self.leading_option(proposal_id);
+
+        // Registrar voto
+        let choice = match self.load_ballot_type(proposal_id) {
+            BallotType::SingleChoice => self.record_single_choice(proposal_id, &ballot, weight),
+            BallotType::RankedChoice => self.record_ranked_ballot(proposal_id, &ballot),
+            BallotType::Approval => self.record_approval_ballot(proposal_id, &ballot, weight),
+            BallotType::Score => self.record_score_ballot(proposal_id, &ballot),
+        };
+        if self.load_voting_power(proposal_id) != VotingPowerMode::OnePerAddress {
+            self.proposal_total_weight(proposal_id).update(|x| *x += weight);
+            self.voter_weight(proposal_id, &caller).set(weight);
+            self.voting_power_used_event(proposal_id, &caller, weight);
+        }
+        if !self.creator_bounty(proposal_id).is_empty() {
+            self.voter_choice(proposal_id, &caller).set(choice);
+        }
+        self.proposal_vote_count(proposal_id).update(|x| *x += 1);
+        self.has_voted(proposal_id, &caller).set(true);
+        self.proposal_voters(proposal_id).push(&caller);
+        // Votos em espaços contam apenas em space_total_votes
+        if self.proposal_space(proposal_id).is_empty() {
+            self.total_votes().update(|x| *x += 1);
+            self.votes_by_voter(&caller).push(&VoteRecord {
+                proposal_id,
+                choice,
+                timestamp: current_time,
+            });
+        }
+
+        self.vote_cast_event(proposal_id, &caller);
+        self.apply_anti_sniping(proposal_id, leader_before, &caller);
+    }
+
+    // A descrição passa a ser o resumo on-chain; o documento completo fica fora da cadeia
+    #[endpoint]
+    fn set_proposal_document(
+        &self,
+        proposal_id: u64,
+        hash_algorithm: HashAlgorithm,
+        content_hash: ManagedBuffer,
+    ) {
+        self.require_not_paused(PauseScope::Creation);
+        self.require_draft_owned_by_caller(proposal_id);
+
+        require!(
+            content_hash.len() <= MAX_CONTENT_HASH_LENGTH,
+            "Invalid content hash"
+        );
+        let mut hash_bytes = [0u8; MAX_CONTENT_HASH_LENGTH];
+        let hash_bytes = content_hash.load_to_byte_array(&mut hash_bytes);
+        require!(
+            hash_algorithm.is_valid_hash(hash_bytes),
+            "Invalid content hash"
+        );
+
+        self.proposal_document(proposal_id).set(&ProposalDocument {
+            hash_algorithm,
+            content_hash: content_hash.clone(),
+        });
+        self.proposal_document_set_event(proposal_id, hash_algorithm, &content_hash);
+    }
+
+    // Quórum e limiar da categoria são copiados para a proposta neste momento
+    #[endpoint]
+    fn set_proposal_category(&self, proposal_id: u64, category_id: usize) {
+        self.require_not_paused(PauseScope::Creation);
+        self.require_draft_owned_by_caller(proposal_id);
+        require!(
+            self.proposal_parent(proposal_id).is_empty(),
+            "Amendment inherits parent category"
+        );
+
+        let (quorum, threshold_bps) = if category_id == UNCATEGORIZED {
+            (0u64, 0u64)
+        } else {
+            self.require_category_exists(category_id);
+            let category = self.categories().get(category_id);
+            require!(
+                self.load_proposal_header(proposal_id).duration >= category.min_duration,
+                "Duration too short for category"
+            );
+            (category.quorum, category.threshold_bps)
+        };
+
+        self.assign_category(proposal_id, category_id, quorum, threshold_bps);
+        self.proposal_category_set_event(proposal_id, category_id);
+    }
+
+    #[endpoint]
+    fn set_proposal_tags(&self, proposal_id: u64, tags: MultiValueEncoded<ManagedBuffer>) {
+        self.require_not_paused(PauseScope::Creation);
+        self.require_draft_owned_by_caller(proposal_id);
+
+        let tags = tags.to_vec();
+        require!(tags.len() <= MAX_TAGS, "Too many tags");
+        for tag in tags.iter() {
+            require!(!tag.is_empty(), "Tag cannot be empty");
+            require!(tag.len() <= MAX_TAG_LENGTH, "Tag too long");
+        }
+
+        self.proposal_tags(proposal_id).set(&tags);
+        self.proposal_tags_set_event(proposal_id, &tags);
+    }
+
+    #[endpoint]
+    fn cancel_proposal(&self, proposal_id: u64) {
+        self.require_not_paused(PauseScope::Cancellation);
+        let caller = self.blockchain().get_caller();
+        
+        // Validações
+        require!(proposal_id < self.total_proposals().get(), "Proposal does not exist");
+        self.require_default_space(proposal_id);
+        require!(self.proposal_active(proposal_id).get(), "Proposal is not active");
+        require!(
+            caller == self.load_proposal_header(proposal_id).creator || caller == self.owner().get(),
+            "Only creator or owner can cancel proposal"
+        );
+
+        self.cancel_with_amendments(proposal_id, &caller);
+    }
+
+    #[endpoint]
+    fn finalize_proposal(&self, proposal_id: u64) {
+        self.require_not_paused(PauseScope::Execution);
+        let caller = self.blockchain().get_caller();
+
+        require!(proposal_id < self.total_proposals().get(), "Proposal does not exist");
+        self.require_default_space(proposal_id);
+
+        if self.close_proposal(proposal_id, &caller) {
+            self.pay_finalization_bounty(proposal_id, &caller);
+        }
+    }
+
+    // Retorna false enquanto a apuração ranqueada não termina
+    fn close_proposal(&self, proposal_id: u64, caller: &ManagedAddress) -> bool {
+        require!(
+            self.compute_proposal_status(proposal_id) == ProposalStatus::Ended,
+            "Proposal cannot be finalized"
+        );
+
+        // A apuração ranqueada pode exigir várias chamadas
+        let outcome = match self.load_ballot_type(proposal_id) {
+            BallotType::RankedChoice => match self.advance_ranked_count(proposal_id) {
+                Some(outcome) => outcome,
+                None => return false,
+            },
+            _ if self.load_seats(proposal_id) > 1 => {
+                self.elect_top_options(proposal_id);
+                self.compute_multi_winner_outcome(proposal_id)
+            },
+            BallotType::SingleChoice | BallotType::Approval | BallotType::Score => {
+                self.compute_proposal_outcome(proposal_id)
+            },
+        };
+        self.proposal_outcome(proposal_id).set(outcome);
+        self.proposal_active(proposal_id).set(false);
+        self.active_proposals().swap_remove(&proposal_id);
+
+        self.proposal_finalized_event(
+            proposal_id,
+            outcome.passed,
+            outcome.winning_option,
+            caller,
+            &self.build_option_votes(proposal_id),
+        );
+
+        // Com a tesouraria pausada o gasto aprovado fica para execute_treasury_spend
+        if self.is_treasury_spend_approved(proposal_id) && !self.is_operation_paused(PauseScope::Treasury) {
+            self.try_execute_treasury_spend(proposal_id);
+        }
+        if !self.prediction_market(proposal_id).is_empty() {
+            self.settle_prediction_market(proposal_id, &outcome);
+        }
+        true
+    }
+
+    #[payable("EGLD")]
+    #[endpoint]
+    fn fund_finalization_pool(&self, #[payment_amount] payment: BigUint) {
+        require!(payment > 0u32, "Payment must be greater than zero");
+        self.finalization_pool().update(|pool| *pool += &payment);
+
+        let caller = self.blockchain().get_caller();
+        self.finalization_pool_funded_event(&caller, &payment);
+    }
+
+    // ============= VOTAÇÃO POR CONVICÇÃO =============
+
+    // Financiamento contínuo: sem prazo, aprovada quando a convicção acumulada
+    // ultrapassa o limiar calculado a partir do valor pedido e do pool de convicção.
+    // O "tamanho do tesouro" da fórmula é esse pool (EGLD via fund_conviction_pool),
+    // não o saldo do tesouro de governança, que só sai por propostas de gasto.
+    // Não há execução agendada: a aprovação é verificada em stake_conviction,
+    // move_conviction e execute_conviction_proposal
+    #[endpoint]
+    fn create_conviction_proposal(
+        &self,
+        title: ManagedBuffer,
+        description: ManagedBuffer,
+        beneficiary: ManagedAddress,
+        requested_amount: BigUint,
+    ) -> u64 {
+        self.require_not_paused(PauseScope::Creation);
+        require!(
+            !self.governance_token().is_empty(),
+            "Governance token not set"
+        );
+        require!(
+            !self.conviction_config().is_empty(),
+            "Conviction voting not configured"
+        );
+        require!(requested_amount > 0u32, "Requested amount must be greater than zero");
+
+        let proposal_id = self.register_proposal(title, description, 0, ManagedVec::new());
+        self.conviction_request(proposal_id).set(ConvictionRequest {
+            beneficiary,
+            amount: requested_amount,
+        });
+        self.open_voting(proposal_id);
+        self.proposal_deadline(proposal_id).set(u64::MAX);
+        self.conviction_last_update(proposal_id)
+            .set(self.blockchain().get_block_timestamp());
+        proposal_id
+    }
+
+    #[payable("EGLD")]
+    #[endpoint]
+    fn fund_conviction_pool(&self, #[payment_amount] payment: BigUint) {
+        require!(payment > 0u32, "Payment must be greater than zero");
+        self.conviction_pool().update(|pool| *pool += &payment);
+
+        let caller = self.blockchain().get_caller();
+        self.conviction_pool_funded_event(&caller, &payment);
+    }
+
+    #[payable("*")]
+    #[endpoint]
+    fn stake_conviction(&self, proposal_id: u64) {
+        self.require_not_paused(PauseScope::Voting);
+        self.require_active_conviction_proposal(proposal_id);
+
+        let payment = self.call_value().single_esdt();
+        require!(
+            payment.token_identifier == self.governance_token().get(),
+            "Invalid governance token"
+        );
+        require!(payment.amount > 0u32, "Payment must be greater than zero");
+
+        let caller = self.blockchain().get_caller();
+        self.conviction_total_staked()
+            .update(|total| *total += &payment.amount);
+        self.add_conviction_support(proposal_id, &caller, &payment.amount);
+        self.try_pass_conviction_proposal(proposal_id);
+    }
+
+    // Saques continuam liberados com o contrato pausado
+    #[endpoint]
+    fn withdraw_conviction(&self, proposal_id: u64, amount: BigUint) {
+        let caller = self.blockchain().get_caller();
+        self.remove_conviction_support(proposal_id, &caller, &amount);
+        self.conviction_total_staked().update(|total| *total -= &amount);
+
+        let token_id = self.governance_token().get();
+        self.tx().to(&caller).single_esdt(&token_id, 0, &amount).transfer();
+    }
+
+    #[endpoint]
+    fn move_conviction(&self, from_proposal_id: u64, to_proposal_id: u64, amount: BigUint) {
+        self.require_not_paused(PauseScope::Voting);
+        require!(from_proposal_id != to_proposal_id, "Cannot move to the same proposal");
+        self.require_active_conviction_proposal(to_proposal_id);
+
+        let caller = self.blockchain().get_caller();
+        self.remove_conviction_support(from_proposal_id, &caller, &amount);
+        self.add_conviction_support(to_proposal_id, &caller, &amount);
+        self.try_pass_conviction_proposal(to_proposal_id);
+    }
+
+    // A convicção cresce com o tempo sem gerar transações; quando o limiar é
+    // atingido só pela passagem do tempo, qualquer um aciona a aprovação por aqui
+    #[endpoint]
+    fn execute_conviction_proposal(&self, proposal_id: u64) {
+        self.require_not_paused(PauseScope::Execution);
+        self.require_active_conviction_proposal(proposal_id);
+        require!(
+            self.try_pass_conviction_proposal(proposal_id),
+            "Conviction threshold not reached"
+        );
+    }
+
+    // ============= VOTE ESCROW =============
+
+    // Trava tokens de governança até `unlock_time`; o poder de voto decai
+    // linearmente até zero na data de desbloqueio
+    #[payable("*")]
+    #[endpoint]
+    fn lock_tokens(&self, unlock_time: u64) {
+        self.require_not_paused(PauseScope::Voting);
+        require!(
+            !self.vote_escrow_config().is_empty(),
+            "Vote escrow not configured"
+        );
+
+        let payment = self.call_value().single_esdt();
+        require!(
+            !self.governance_token().is_empty()
+                && payment.token_identifier == self.governance_token().get(),
+            "Invalid governance token"
+        );
+        require!(payment.amount > 0u32, "Payment must be greater than zero");
+
+        let caller = self.blockchain().get_caller();
+        let current_time = self.blockchain().get_block_timestamp();
+        let lock = self.load_vote_escrow_lock(&caller);
+        if lock.amount > 0u32 {
+            require!(current_time < lock.unlock_time, "Withdraw expired lock first");
+            require!(unlock_time >= lock.unlock_time, "Cannot shorten lock");
+        }
+        self.require_valid_unlock_time(unlock_time);
+
+        let amount = lock.amount + &payment.amount;
+        self.vote_escrow_total_locked()
+            .update(|total| *total += &payment.amount);
+        self.push_vote_escrow_checkpoint(&caller, amount, unlock_time);
+    }
+
+    #[endpoint]
+    fn extend_lock(&self, unlock_time: u64) {
+        self.require_not_paused(PauseScope::Voting);
+
+        let caller = self.blockchain().get_caller();
+        let lock = self.load_vote_escrow_lock(&caller);
+        require!(lock.amount > 0u32, "No active lock");
+        require!(
+            self.blockchain().get_block_timestamp() < lock.unlock_time,
+            "Lock expired"
+        );
+        require!(unlock_time > lock.unlock_time, "Cannot shorten lock");
+        self.require_valid_unlock_time(unlock_time);
+
+        self.push_vote_escrow_checkpoint(&caller, lock.amount, unlock_time);
+    }
+
+    // Saques continuam liberados com o contrato pausado
+    #[endpoint]
+    fn withdraw_lock(&self) {
+        let caller = self.blockchain().get_caller();
+        let lock = self.load_vote_escrow_lock(&caller);
+        require!(lock.amount > 0u32, "No active lock");
+        require!(
+            self.blockchain().get_block_timestamp() >= lock.unlock_time,
+            "Lock has not expired"
+        );
+
+        self.vote_escrow_total_locked()
+            .update(|total| *total -= &lock.amount);
+        self.push_vote_escrow_checkpoint(&caller, BigUint::zero(), 0);
+
+        let token_id = self.governance_token().get();
+        self.tx().to(&caller).single_esdt(&token_id, 0, &lock.amount).transfer();
+    }
+
+    // ============= VOTAÇÃO COM NFT =============
+
+    // NFTs retidos voltam ao eleitor quando a votação deixa de estar ativa
+    #[endpoint]
+    fn claim_voting_nfts(&self, proposal_id: u64) {
+        require!(proposal_id < self.total_proposals().get(), "Proposal does not exist");
+        self.require_default_space(proposal_id);
+        require!(
+            self.compute_proposal_status(proposal_id) != ProposalStatus::Active,
+            "Voting still in progress"
+        );
+
+        let caller = self.blockchain().get_caller();
+        let mut deposits = self.nft_deposits(proposal_id, &caller);
+        require!(!deposits.is_empty(), "No NFTs to claim");
+
+        let payments: ManagedVec<EsdtTokenPayment> = deposits.iter().collect();
+        deposits.clear();
+        self.tx().to(&caller).payment(payments).transfer();
+    }
+
+    // ============= TESOURARIA =============
+
+    #[payable("*")]
+    #[endpoint]
+    fn deposit_to_treasury(&self) {
+        self.require_not_paused(PauseScope::Treasury);
+        let caller = self.blockchain().get_caller();
+
+        let egld_amount = self.call_value().egld_value().clone();
+        let esdt_payments = self.call_value().all_esdt_transfers().clone();
+        require!(
+            egld_amount > 0u32 || !esdt_payments.is_empty(),
+            "Payment must be greater than zero"
+        );
+
+        if egld_amount > 0u32 {
+            self.credit_treasury(&caller, &EgldOrEsdtTokenIdentifier::egld(), 0, &egld_amount);
+        }
+        for payment in esdt_payments.iter() {
+            require!(payment.token_nonce == 0, "Treasury only holds fungible tokens");
+            self.credit_treasury(
+                &caller,
+                &EgldOrEsdtTokenIdentifier::esdt(payment.token_identifier.clone()),
+                payment.token_nonce,
+                &payment.amount,
+            );
+        }
+    }
+
+    // Repete um gasto aprovado que não pôde ser pago na finalização por falta de saldo
+    #[endpoint]
+    fn execute_treasury_spend(&self, proposal_id: u64) {
+        self.require_not_paused(PauseScope::Treasury);
+        require!(proposal_id < self.total_proposals().get(), "Proposal does not exist");
+        self.require_default_space(proposal_id);
+        require!(
+            self.is_treasury_spend_approved(proposal_id),
+            "Treasury spend not approved"
+        );
+        require!(
+            self.try_execute_treasury_spend(proposal_id),
+            "Insufficient treasury balance"
+        );
+    }
+
+    #[endpoint]
+    fn claim_stream(&self, stream_id: usize) {
+        self.require_not_paused(PauseScope::Treasury);
+        let caller = self.blockchain().get_caller();
+
+        require!(
+            stream_id > 0 && stream_id <= self.vesting_streams().len(),
+            "Stream does not exist"
+        );
+        let mut stream = self.vesting_streams().get(stream_id);
+        require!(stream.recipient == caller, "Only the recipient can claim");
+
+        let claimable = self.stream_claimable(&stream);
+        require!(claimable > 0u32, "Nothing to claim");
+
+        stream.claimed += &claimable;
+        self.vesting_streams().set(stream_id, &stream);
+
+        self.tx()
+            .to(&caller)
+            .egld_or_single_esdt(&stream.token_id, stream.token_nonce, &claimable)
+            .transfer();
+        self.stream_claimed_event(stream_id, &caller, &claimable);
+    }
+
+    // Executa uma proposta de cancelamento aprovada: o que já foi liberado continua
+    // resgatável pelo beneficiário e o restante volta para a tesouraria
+    #[endpoint]
+    fn cancel_stream(&self, proposal_id: u64) {
+        self.require_not_paused(PauseScope::Treasury);
+        require!(proposal_id < self.total_proposals().get(), "Proposal does not exist");
+        self.require_default_space(proposal_id);
+        require!(
+            !self.stream_cancellation(proposal_id).is_empty(),
+            "Not a stream cancellation proposal"
+        );
+        require!(
+            self.is_governance_action_approved(proposal_id),
+            "Stream cancellation not approved"
+        );
+
+        let stream_id = self.stream_cancellation(proposal_id).get();
+        self.require_open_stream(stream_id);
+
+        let current_time = self.blockchain().get_block_timestamp();
+        let mut stream = self.vesting_streams().get(stream_id);
+        stream.cancelled_at = Some(current_time);
+        let unvested = &stream.amount - &self.stream_vested(&stream);
+        self.vesting_streams().set(stream_id, &stream);
+
+        if unvested > 0u32 {
+            let own_address = self.blockchain().get_sc_address();
+            self.credit_treasury(&own_address, &stream.token_id, stream.token_nonce, &unvested);
+        }
+        self.stream_cancelled_event(stream_id, proposal_id, &unvested);
+    }
+
+    // ============= RECOMPENSAS DE VOTAÇÃO =============
+
+    // O primeiro depósito fixa o token do pool da proposta
+    #[payable("*")]
+    #[endpoint]
+    fn fund_voting_reward(&self, proposal_id: u64) {
+        self.require_not_paused(PauseScope::Treasury);
+        let caller = self.blockchain().get_caller();
+
+        require!(proposal_id < self.total_proposals().get(), "Proposal does not exist");
+        self.require_default_space(proposal_id);
+        require!(
+            self.conviction_request(proposal_id).is_empty(),
+            "Conviction proposals use staking"
+        );
+        let status = self.compute_proposal_status(proposal_id);
+        require!(
+            status == ProposalStatus::Draft
+                || status == ProposalStatus::Active
+                || status == ProposalStatus::Ended,
+            "Proposal cannot receive rewards"
+        );
+
+        let payment = self.call_value().egld_or_single_esdt();
+        require!(payment.amount > 0u32, "Payment must be greater than zero");
+
+        let pool_mapper = self.voting_reward_pool(proposal_id);
+        if pool_mapper.is_empty() {
+            pool_mapper.set(&payment);
+        } else {
+            let mut pool = pool_mapper.get();
+            require!(
+                pool.token_identifier == payment.token_identifier
+                    && pool.token_nonce == payment.token_nonce,
+                "Reward token mismatch"
+            );
+            pool.amount += &payment.amount;
+            pool_mapper.set(&pool);
+        }
+        self.voting_reward_contribution(proposal_id, &caller)
+            .update(|contribution| *contribution += &payment.amount);
+
+        self.voting_reward_funded_event(proposal_id, &caller, &payment.amount);
+    }
+
+    #[endpoint]
+    fn claim_voting_reward(&self, proposal_id: u64) {
+        self.require_not_paused(PauseScope::Treasury);
+        let caller = self.blockchain().get_caller();
+        let current_time = self.blockchain().get_block_timestamp();
+
+        require!(proposal_id < self.total_proposals().get(), "Proposal does not exist");
+        self.require_default_space(proposal_id);
+        require!(!self.voting_reward_pool(proposal_id).is_empty(), "No voting reward");
+        require!(
+            !self.proposal_outcome(proposal_id).is_empty(),
+            "Proposal not finalized"
+        );
+        require!(
+            current_time <= self.voting_reward_deadline(proposal_id),
+            "Reward claim period ended"
+        );
+        require!(
+            !self.voting_reward_claimed(proposal_id, &caller).get(),
+            "Reward already claimed"
+        );
+
+        let reward = self.compute_voting_reward(proposal_id, &caller);
+        require!(reward > 0u32, "Nothing to claim");
+
+        self.voting_reward_claimed(proposal_id, &caller).set(true);
+        self.voting_reward_distributed(proposal_id)
+            .update(|distributed| *distributed += &reward);
+
+        let pool = self.voting_reward_pool(proposal_id).get();
+        self.tx()
+            .to(&caller)
+            .egld_or_single_esdt(&pool.token_identifier, pool.token_nonce, &reward)
+            .transfer();
+        self.voting_reward_claimed_event(proposal_id, &caller, &reward);
+    }
+
+    // Proposta cancelada ou finalizada sem eleitores nunca distribui nada:
+    // cada financiador recebe de volta o que depositou
+    #[endpoint]
+    fn refund_voting_reward(&self, proposal_id: u64) {
+        self.require_not_paused(PauseScope::Treasury);
+        let caller = self.blockchain().get_caller();
+
+        require!(proposal_id < self.total_proposals().get(), "Proposal does not exist");
+        self.require_default_space(proposal_id);
+        require!(!self.voting_reward_pool(proposal_id).is_empty(), "No voting reward");
+        require!(
+            self.is_voting_reward_refundable(proposal_id),
+            "Voting reward not refundable"
+        );
+
+        let contribution = self.voting_reward_contribution(proposal_id, &caller).take();
+        require!(contribution > 0u32, "Nothing to refund");
+
+        self.voting_reward_distributed(proposal_id)
+            .update(|distributed| *distributed += &contribution);
+
+        let pool = self.voting_reward_pool(proposal_id).get();
+        self.tx()
+            .to(&caller)
+            .egld_or_single_esdt(&pool.token_identifier, pool.token_nonce, &contribution)
+            .transfer();
+        self.voting_reward_refunded_event(proposal_id, &caller, &contribution);
+    }
+
+    // Sobras (não resgatadas ou de arredondamento) vão para a tesouraria; sem
+    // eleitores elegíveis o pool inteiro volta aos financiadores via refund
+    #[endpoint]
+    fn sweep_voting_reward(&self, proposal_id: u64) {
+        self.require_not_paused(PauseScope::Treasury);
+        let current_time = self.blockchain().get_block_timestamp();
+
+        require!(proposal_id < self.total_proposals().get(), "Proposal does not exist");
+        self.require_default_space(proposal_id);
+        require!(!self.voting_reward_pool(proposal_id).is_empty(), "No voting reward");
+        require!(
+            !self.proposal_outcome(proposal_id).is_empty()
+                && current_time > self.voting_reward_deadline(proposal_id),
+            "Reward claim period not ended"
+        );
+
+        require!(
+            self.load_total_weight(proposal_id) > 0,
+            "No eligible voters, funders can refund"
+        );
+
+        let pool = self.voting_reward_pool(proposal_id).get();
+        let remainder = &pool.amount - &self.voting_reward_distributed(proposal_id).get();
+        require!(remainder > 0u32, "Nothing to sweep");
+
+        self.voting_reward_distributed(proposal_id).set(&pool.amount);
+        let own_address = self.blockchain().get_sc_address();
+        self.credit_treasury(&own_address, &pool.token_identifier, pool.token_nonce, &remainder);
+        self.voting_reward_swept_event(proposal_id, &remainder);
+    }
+
+    // ============= RECOMPENSA DO CRIADOR =============
+
+    #[endpoint]
+    fn claim_creator_bounty(&self, proposal_id: u64) {
+        let caller = self.blockchain().get_caller();
+        let current_time = self.blockchain().get_block_timestamp();
+
+        require!(proposal_id < self.total_proposals().get(), "Proposal does not exist");
+        self.require_default_space(proposal_id);
+        require!(!self.creator_bounty(proposal_id).is_empty(), "Proposal has no bounty");
+        require!(
+            !self.proposal_outcome(proposal_id).is_empty(),
+            "Proposal not finalized"
+        );
+        require!(
+            current_time <= self.voting_reward_deadline(proposal_id),
+            "Bounty claim period ended"
+        );
+        require!(
+            !self.creator_bounty_claimed(proposal_id, &caller).get(),
+            "Bounty already claimed"
+        );
+
+        let share = self.compute_creator_bounty_share(proposal_id, &caller);
+        require!(share > 0u32, "Nothing to claim");
+
+        self.creator_bounty_claimed(proposal_id, &caller).set(true);
+        self.creator_bounty_distributed(proposal_id)
+            .update(|distributed| *distributed += &share);
+
+        let bounty = self.creator_bounty(proposal_id).get();
+        self.tx()
+            .to(&caller)
+            .egld_or_single_esdt(&bounty.token_identifier, bounty.token_nonce, &share)
+            .transfer();
+        self.creator_bounty_claimed_event(proposal_id, &caller, &share);
+    }
+
+    // O criador recupera a recompensa quando a regra manda devolver, quando não há
+    // eleitores elegíveis, quando a proposta é cancelada ou, após o prazo, o que sobrou
+    #[endpoint]
+    fn reclaim_creator_bounty(&self, proposal_id: u64) {
+        let caller = self.blockchain().get_caller();
+        let current_time = self.blockchain().get_block_timestamp();
+
+        require!(proposal_id < self.total_proposals().get(), "Proposal does not exist");
+        self.require_default_space(proposal_id);
+        require!(!self.creator_bounty(proposal_id).is_empty(), "Proposal has no bounty");
+        require!(
+            caller == self.load_proposal_header(proposal_id).creator,
+            "Only creator can reclaim the bounty"
+        );
+
+        let status = self.compute_proposal_status(proposal_id);
+        let finalized = !self.proposal_outcome(proposal_id).is_empty();
+        require!(
+            status == ProposalStatus::Cancelled
+                || (finalized
+                    && (self.load_creator_bounty_rule(proposal_id) == BountyRule::ReturnToCreator
+                        || self.creator_bounty_eligible_weight(proposal_id) == 0
+                        || current_time > self.voting_reward_deadline(proposal_id))),
+            "Bounty cannot be reclaimed yet"
+        );
+
+        let bounty = self.creator_bounty(proposal_id).get();
+        let remainder = &bounty.amount - &self.creator_bounty_distributed(proposal_id).get();
+        require!(remainder > 0u32, "Nothing to reclaim");
+
+        self.creator_bounty_distributed(proposal_id).set(&bounty.amount);
+        self.tx()
+            .to(&caller)
+            .egld_or_single_esdt(&bounty.token_identifier, bounty.token_nonce, &remainder)
+            .transfer();
+        self.creator_bounty_reclaimed_event(proposal_id, &caller, &remainder);
+    }
+
+    // ============= UPGRADE POR GOVERNANÇA =============
+
+    // Para o upgrade funcionar o contrato precisa ser dono de si mesmo
+    // (ChangeOwnerAddress para o próprio endereço), tirando do deployer o controle do código
+    #[endpoint]
+    fn execute_code_upgrade(&self, proposal_id: u64, code: ManagedBuffer) {
+        self.require_not_paused(PauseScope::Execution);
+        let current_time = self.blockchain().get_block_timestamp();
+
+        require!(proposal_id < self.total_proposals().get(), "Proposal does not exist");
+        self.require_default_space(proposal_id);
+        require!(
+            !self.code_upgrade(proposal_id).is_empty(),
+            "Not a code upgrade proposal"
+        );
+        require!(
+            !self.code_upgrade_executed(proposal_id).get(),
+            "Code upgrade already executed"
+        );
+        require!(
+            self.is_governance_action_approved(proposal_id),
+            "Code upgrade not approved"
+        );
+        require!(
+            current_time >= self.code_upgrade_eta(proposal_id),
+            "Upgrade delay not elapsed"
+        );
+
+        let upgrade = self.code_upgrade(proposal_id).get();
+        let code_hash = self.crypto().sha256(&code);
+        require!(
+            code_hash.as_managed_buffer() == &upgrade.code_hash,
+            "Code hash mismatch"
+        );
+
+        // Sem isso o upgrade assíncrono falharia depois de marcar a proposta como executada
+        let own_address = self.blockchain().get_sc_address();
+        require!(
+            self.blockchain().get_owner_address() == own_address,
+            "Contract must own itself to upgrade"
+        );
+
+        self.code_upgrade_executed(proposal_id).set(true);
+        self.code_upgrade_executed_event(proposal_id, &upgrade.code_hash);
+
+        self.tx()
+            .to(&own_address)
+            .raw_upgrade()
+            .code(code)
+            .code_metadata(upgrade.code_metadata)
+            .upgrade_async_call_and_exit();
+    }
+
+    // ============= MERCADO DE PREVISÃO =============
+
+    // A aposta não altera a apuração; cada endereço aposta em uma única opção
+    #[payable("*")]
+    #[endpoint]
+    fn stake_prediction(&self, proposal_id: u64, option: usize) {
+        self.require_not_paused(PauseScope::Voting);
+        let caller = self.blockchain().get_caller();
+
+        require!(proposal_id < self.total_proposals().get(), "Proposal does not exist");
+        self.require_default_space(proposal_id);
+        require!(
+            !self.prediction_market(proposal_id).is_empty(),
+            "Not a prediction market"
+        );
+        require!(
+            self.compute_proposal_status(proposal_id) == ProposalStatus::Active,
+            "Proposal is not active"
+        );
+        require!(
+            option < self.proposal_options(proposal_id).len(),
+            "Invalid option"
+        );
+
+        let market = self.prediction_market(proposal_id).get();
+        let payment = self.call_value().egld_or_single_esdt();
+        require!(
+            payment.token_identifier == market.token_id && payment.token_nonce == 0,
+            "Invalid stake token"
+        );
+        require!(payment.amount > 0u32, "Payment must be greater than zero");
+
+        let position_mapper = self.prediction_position(proposal_id, &caller);
+        let mut position = if position_mapper.is_empty() {
+            PredictionPosition {
+                option,
+                amount: BigUint::zero(),
+            }
+        } else {
+            position_mapper.get()
+        };
+        require!(position.option == option, "Already staked on another option");
+
+        position.amount += &payment.amount;
+        position_mapper.set(&position);
+        self.prediction_option_staked(proposal_id, option)
+            .update(|staked| *staked += &payment.amount);
+        self.prediction_total_staked(proposal_id)
+            .update(|staked| *staked += &payment.amount);
+
+        self.prediction_staked_event(proposal_id, &caller, option, &payment.amount);
+    }
+
+    // Vencedores recebem a aposta de volta mais a parte proporcional do lado perdedor;
+    // sem vencedor apostado, ou em propostas canceladas, todas as apostas são devolvidas
+    #[endpoint]
+    fn claim_prediction(&self, proposal_id: u64) {
+        let caller = self.blockchain().get_caller();
+
+        require!(proposal_id < self.total_proposals().get(), "Proposal does not exist");
+        self.require_default_space(proposal_id);
+        require!(
+            !self.prediction_market(proposal_id).is_empty(),
+            "Not a prediction market"
+        );
+        require!(
+            !self.proposal_outcome(proposal_id).is_empty()
+                || self.compute_proposal_status(proposal_id) == ProposalStatus::Cancelled,
+            "Proposal not finalized"
+        );
+        require!(
+            !self.prediction_position(proposal_id, &caller).is_empty(),
+            "No prediction stake"
+        );
+        require!(
+            !self.prediction_claimed(proposal_id, &caller).get(),
+            "Prediction already claimed"
+        );
+
+        let payout = self.compute_prediction_payout(proposal_id, &caller);
+        require!(payout > 0u32, "Nothing to claim");
+        self.prediction_claimed(proposal_id, &caller).set(true);
+
+        let position = self.prediction_position(proposal_id, &caller).get();
+        if self.is_prediction_winner(proposal_id, &position) {
+            self.track_prediction_winnings(proposal_id, &position, &payout);
+        }
+
+        let market = self.prediction_market(proposal_id).get();
+        self.tx()
+            .to(&caller)
+            .egld_or_single_esdt(&market.token_id, 0, &payout)
+            .transfer();
+        self.prediction_claimed_event(proposal_id, &caller, &payout);
+    }
+
+    // ============= ESPAÇOS =============
+
+    // Cada espaço tem administradores, registro de eleitores, regras e numeração
+    // de propostas próprios. Os endpoints sem espaço seguem operando no espaço
+    // padrão (id 0), administrado pelo dono do contrato e aberto a qualquer eleitor.
+    // Propostas de espaços são votações simples de escolha única: rascunhos, emendas,
+    // categorias, tesouraria, streams e recompensas existem apenas no espaço padrão,
+    // e os endpoints globais recusam ids de propostas de espaços. Contadores e
+    // listagens globais cobrem só o espaço padrão; as views por id aceitam qualquer
+    // id global (ver get_proposal_space e get_space_proposal_id).
+    #[endpoint]
+    fn create_space(&self, name: ManagedBuffer, admin: ManagedAddress) -> usize {
+        self.require_owner();
+        require!(!name.is_empty(), "Space name cannot be empty");
+        require!(name.len() <= MAX_SPACE_NAME_LENGTH, "Space name too long");
+        require!(!admin.is_zero(), "Invalid admin address");
+        require!(self.space_names().len() < MAX_SPACES, "Maximum spaces exceeded");
+
+        // VecMapper começa em 1, então o id 0 fica reservado para o espaço padrão
+        let space_id = self.space_names().len() + 1;
+        self.space_names().push(&name);
+        self.space_admins(space_id).insert(admin.clone());
+        self.space_config(space_id).set(SpaceConfig {
+            quorum: 0,
+            threshold_bps: 0,
+            min_duration: MIN_DURATION,
+        });
+
+        self.space_created_event(space_id, &admin, &name);
+        space_id
+    }
+
+    #[endpoint]
+    fn add_space_admin(&self, space_id: usize, admin: ManagedAddress) {
+        self.require_space_admin(space_id);
+        require!(!admin.is_zero(), "Invalid admin address");
+        require!(self.space_admins(space_id).insert(admin.clone()), "Already a space admin");
+
+        self.space_admin_added_event(space_id, &admin);
+    }
+
+    #[endpoint]
+    fn remove_space_admin(&self, space_id: usize, admin: ManagedAddress) {
+        self.require_space_admin(space_id);
+        require!(self.space_admins(space_id).len() > 1, "Space must keep an admin");
+        require!(self.space_admins(space_id).swap_remove(&admin), "Not a space admin");
+
+        self.space_admin_removed_event(space_id, &admin);
+    }
+
+    #[endpoint]
+    fn register_space_voters(&self, space_id: usize, voters: MultiValueEncoded<ManagedAddress>) {
+        self.require_space_admin(space_id);
+        require!(
+            voters.len() <= MAX_SPACE_VOTERS_PER_CALL,
+            "Too many voters per call"
+        );
+
+        for voter in voters {
+            if self.space_voters(space_id).insert(voter.clone()) {
+                self.space_voter_registered_event(space_id, &voter);
+            }
+        }
+    }
+
+    #[endpoint]
+    fn remove_space_voters(&self, space_id: usize, voters: MultiValueEncoded<ManagedAddress>) {
+        self.require_space_admin(space_id);
+        require!(
+            voters.len() <= MAX_SPACE_VOTERS_PER_CALL,
+            "Too many voters per call"
+        );
+
+        for voter in voters {
+            if self.space_voters(space_id).swap_remove(&voter) {
+                self.space_voter_removed_event(space_id, &voter);
+            }
+        }
+    }
+
+    // Alterações valem apenas para propostas criadas depois da atualização
+    #[endpoint]
+    fn set_space_config(&self, space_id: usize, quorum: u64, threshold_bps: u64, min_duration: u64) {
+        self.require_space_admin(space_id);
+        self.require_valid_category_rules(threshold_bps, min_duration);
+
+        self.space_config(space_id).set(SpaceConfig {
+            quorum,
+            threshold_bps,
+            min_duration,
+        });
+        self.space_config_updated_event(space_id, quorum, threshold_bps, min_duration);
+    }
+
+    // Retorna o id da proposta dentro do espaço
+    #[endpoint]
+    fn create_space_proposal(
+        &self,
+        space_id: usize,
+        title: ManagedBuffer,
+        description: ManagedBuffer,
+        duration: u64,
+        options: MultiValueEncoded<ManagedBuffer>,
+    ) -> u64 {
+        self.require_not_paused(PauseScope::Creation);
+        self.require_space_exists(space_id);
+
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.space_admins(space_id).contains(&caller) || self.space_voters(space_id).contains(&caller),
+            "Only space members can create proposals"
+        );
+
+        let config = self.space_config(space_id).get();
+        require!(duration >= config.min_duration, "Duration too short");
+
+        // Limite por usuário contado separadamente em cada espaço
+        let user_proposals_mapper = self.space_user_proposal_count(space_id, &caller);
+        require!(
+            user_proposals_mapper.get() < MAX_PROPOSALS_PER_USER,
+            "Maximum proposals per user exceeded"
+        );
+
+        let options = options.to_vec();
+        self.require_valid_proposal_content(&title, &description, &options);
+        let proposal_id = self.store_proposal(&caller, title, description, duration, options);
+        user_proposals_mapper.update(|x| *x += 1);
+        self.proposal_quorum(proposal_id).set(config.quorum);
+        self.proposal_threshold_bps(proposal_id).set(config.threshold_bps);
+
+        let local_id = self.space_proposals(space_id).len() as u64;
+        self.space_proposals(space_id).push(&proposal_id);
+        self.proposal_space(proposal_id).set(space_id);
+        self.space_proposal_count().update(|x| *x += 1);
+        self.open_voting(proposal_id);
+
+        self.space_proposal_created_event(space_id, local_id, proposal_id);
+        local_id
+    }
+
+    // Apenas eleitores registrados no espaço podem votar
+    #[endpoint]
+    fn vote_in_space(&self, space_id: usize, local_id: u64, ballot: MultiValueEncoded<usize>) {
+        self.require_not_paused(PauseScope::Voting);
+        let proposal_id = self.resolve_space_proposal(space_id, local_id);
+
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.space_voters(space_id).contains(&caller),
+            "Not a registered voter in this space"
+        );
+
+        self.cast_vote(proposal_id, ballot.to_vec());
+        self.space_total_votes(space_id).update(|x| *x += 1);
+    }
+
+    #[endpoint]
+    fn cancel_space_proposal(&self, space_id: usize, local_id: u64) {
+        self.require_not_paused(PauseScope::Cancellation);
+        let proposal_id = self.resolve_space_proposal(space_id, local_id);
+
+        let caller = self.blockchain().get_caller();
+        require!(self.proposal_active(proposal_id).get(), "Proposal is not active");
+        require!(
+            caller == self.load_proposal_header(proposal_id).creator
+                || self.space_admins(space_id).contains(&caller),
+            "Only creator or space admin can cancel proposal"
+        );
+
+        self.cancel_with_amendments(proposal_id, &caller);
+    }
+
+    // O pool de finalização paga apenas propostas do espaço padrão
+    #[endpoint]
+    fn finalize_space_proposal(&self, space_id: usize, local_id: u64) {
+        self.require_not_paused(PauseScope::Execution);
+        let proposal_id = self.resolve_space_proposal(space_id, local_id);
+
+        let caller = self.blockchain().get_caller();
+        self.close_proposal(proposal_id, &caller);
+    }
+
+    // ============= VIEWS SIMPLES =============
+
+    #[view]
+    fn get_proposal_title(&self, proposal_id: u64) -> ManagedBuffer {
+        require!(
+            proposal_id < self.total_proposals().get(),
+            "Proposal does not exist"
+        );
+        self.load_proposal_header(proposal_id).title
+    }
+
+    #[view]
+    fn get_proposal_description(&self, proposal_id: u64) -> ManagedBuffer {
+        require!(
+            proposal_id < self.total_proposals().get(),
+            "Proposal does not exist"
+        );
+        self.load_proposal_header(proposal_id).description
+    }
+
+    #[view]
+    fn get_proposal_creator(&self, proposal_id: u64) -> ManagedAddress {
+        require!(
+            proposal_id < self.total_proposals().get(),
+            "Proposal does not exist"
+        );
+        self.load_proposal_header(proposal_id).creator
+    }
+
+    #[view]
+    fn get_proposal_vote_count(&self, proposal_id: u64) -> u64 {
+        require!(
+            proposal_id < self.total_proposals().get(),
+            "Proposal does not exist"
+        );
+        self.proposal_vote_count(proposal_id).get()
+    }
+
+    #[view]
+    fn get_proposal_deadline(&self, proposal_id: u64) -> u64 {
+        require!(
+            proposal_id < self.total_proposals().get(),
+            "Proposal does not exist"
+        );
+        self.proposal_deadline(proposal_id).get()
+    }
+
+    #[view]
+    fn is_proposal_active(&self, proposal_id: u64) -> bool {
+        require!(
+            proposal_id < self.total_proposals().get(),
+            "Proposal does not exist"
+        );
+        self.proposal_active(proposal_id).get()
+    }
+
+    // Quantidade de ids globais, incluindo propostas de espaços
+    #[view]
+    fn get_total_proposals(&self) -> u64 {
+        self.total_proposals().get()
+    }
+
+    // Apenas propostas do espaço padrão; ver get_space_total_proposals
+    #[view]
+    fn get_default_proposal_count(&self) -> u64 {
+        self.total_proposals().get() - self.space_proposal_count().get()
+    }
+
+    #[view]
+    fn get_total_votes(&self) -> u64 {
+        self.total_votes().get()
+    }
+
+    #[view]
+    fn has_user_voted_on_proposal(&self, proposal_id: u64, user_address: ManagedAddress) -> bool {
+        require!(
+            proposal_id < self.total_proposals().get(),
+            "Proposal does not exist"
+        );
+        self.has_voted(proposal_id, &user_address).get()
+    }
+
+    // Reflete apenas a pausa geral (pause/unpause); ver get_pause_state
+    #[view]
+    fn is_contract_paused(&self) -> bool {
+        self.is_paused().get()
+    }
+
+    #[view]
+    fn is_operation_paused(&self, scope: PauseScope) -> bool {
+        self.load_operation_pause(scope).paused
+    }
+
+    #[view]
+    fn get_pause_state(&self) -> PauseState {
+        PauseState {
+            contract_paused: self.is_paused().get(),
+            creation: self.load_operation_pause(PauseScope::Creation),
+            voting: self.load_operation_pause(PauseScope::Voting),
+            cancellation: self.load_operation_pause(PauseScope::Cancellation),
+            execution: self.load_operation_pause(PauseScope::Execution),
+            treasury: self.load_operation_pause(PauseScope::Treasury),
+        }
+    }
+
+    #[view]
+    fn get_pausers(&self) -> MultiValueEncoded<ManagedAddress> {
+        self.pausers().iter().collect()
+    }
+
+    #[view]
+    fn is_pauser(&self, address: ManagedAddress) -> bool {
+        self.pausers().contains(&address)
+    }
+
+    #[view]
+    fn get_owner(&self) -> ManagedAddress {
+        self.owner().get()
+    }
+
+    // ============= VIEWS DE LISTAGEM =============
+
+    #[view]
+    fn get_proposal(&self, proposal_id: u64) -> Proposal<Self::Api> {
+        require!(
+            proposal_id < self.total_proposals().get(),
+            "Proposal does not exist"
+        );
+        self.build_proposal(proposal_id)
+    }
+
+    #[view]
+    fn get_proposal_status(&self, proposal_id: u64) -> ProposalStatus {
+        require!(
+            proposal_id < self.total_proposals().get(),
+            "Proposal does not exist"
+        );
+        self.compute_proposal_status(proposal_id)
+    }
+
+    // `from` é a posição entre as propostas do espaço padrão; o total dessas
+    // posições é get_default_proposal_count
+    #[view]
+    fn get_proposals(&self, from: u64, count: usize) -> MultiValueEncoded<Proposal<Self::Api>> {
+        let total = self.total_proposals().get();
+        // Sem espaços, posição e id coincidem
+        if self.space_proposal_count().get() == 0 {
+            let page_size = core::cmp::min(count, MAX_PAGE_SIZE) as u64;
+            let end = core::cmp::min(from.saturating_add(page_size), total);
+
+            let mut result = MultiValueEncoded::new();
+            for proposal_id in from..end {
+                result.push(self.build_proposal(proposal_id));
+            }
+            return result;
+        }
+
+        let page_size = core::cmp::min(count, MAX_PAGE_SIZE);
+        let mut result = MultiValueEncoded::new();
+        let mut skipped = 0u64;
+        let mut collected = 0usize;
+        for proposal_id in 0..total {
+            if collected == page_size {
+                break;
+            }
+            if !self.proposal_space(proposal_id).is_empty() {
+                continue;
+            }
+            if skipped < from {
+                skipped += 1;
+                continue;
+            }
+
+            result.push(self.build_proposal(proposal_id));
+            collected += 1;
+        }
+        result
+    }
+
+    // `from` é o deslocamento dentro da lista já filtrada pelo status.
+    // Propostas `Active` e `Ended` são lidas do índice de propostas abertas;
+    // os demais status percorrem o histórico.
+    #[view]
+    fn get_proposals_by_status(
+        &self,
+        status: ProposalStatus,
+        from: usize,
+        count: usize,
+    ) -> MultiValueEncoded<Proposal<Self::Api>> {
+        match status {
+            ProposalStatus::Active | ProposalStatus::Ended => {
+                self.collect_proposals_with_status(self.active_proposals().iter(), status, from, count)
+            },
+            ProposalStatus::Draft
+            | ProposalStatus::Cancelled
+            | ProposalStatus::Passed
+            | ProposalStatus::Rejected => {
+                let total = self.total_proposals().get();
+                self.collect_proposals_with_status(0..total, status, from, count)
+            },
+        }
+    }
+
+    #[view]
+    fn get_active_proposal_count(&self) -> usize {
+        self.active_proposals()
+            .iter()
+            .filter(|proposal_id| self.proposal_space(*proposal_id).is_empty())
+            .count()
+    }
+
+    #[view]
+    fn get_proposal_options(&self, proposal_id: u64) -> MultiValueEncoded<ManagedBuffer> {
+        require!(
+            proposal_id < self.total_proposals().get(),
+            "Proposal does not exist"
+        );
+        self.proposal_options(proposal_id).iter().collect()
+    }
+
+    #[view]
+    fn get_option_vote_count(&self, proposal_id: u64, option: usize) -> u64 {
+        require!(
+            proposal_id < self.total_proposals().get(),
+            "Proposal does not exist"
+        );
+        self.proposal_option_votes(proposal_id, option).get()
+    }
+
+    #[view]
+    fn get_proposal_voters(
+        &self,
+        proposal_id: u64,
+        from: usize,
+        count: usize,
+    ) -> MultiValueEncoded<ManagedAddress> {
+        require!(
+            proposal_id < self.total_proposals().get(),
+            "Proposal does not exist"
+        );
+
+        let mapper = self.proposal_voters(proposal_id);
+        let (start, end) = self.page_bounds(from, count, mapper.len());
+
+        let mut result = MultiValueEncoded::new();
+        for index in start..end {
+            result.push(mapper.get(index + 1));
+        }
+        result
+    }
+
+    #[view]
+    fn get_proposal_voter_count(&self, proposal_id: u64) -> usize {
+        require!(
+            proposal_id < self.total_proposals().get(),
+            "Proposal does not exist"
+        );
+        self.proposal_voters(proposal_id).len()
+    }
+
+    #[view]
+    fn get_proposal_document(&self, proposal_id: u64) -> OptionalValue<ProposalDocument<Self::Api>> {
+        require!(
+            proposal_id < self.total_proposals().get(),
+            "Proposal does not exist"
+        );
+        self.load_proposal_document(proposal_id).into()
+    }
+
+    // ============= VIEWS DE EMENDAS E RESULTADO =============
+
+    #[view]
+    fn get_proposal_amendments(&self, proposal_id: u64) -> MultiValueEncoded<u64> {
+        require!(
+            proposal_id < self.total_proposals().get(),
+            "Proposal does not exist"
+        );
+        self.proposal_amendments(proposal_id).iter().collect()
+    }
+
+    #[view]
+    fn get_amendment_parent(&self, proposal_id: u64) -> OptionalValue<u64> {
+        require!(
+            proposal_id < self.total_proposals().get(),
+            "Proposal does not exist"
+        );
+        self.proposal_parent(proposal_id).get().into()
+    }
+
+    // Parcial enquanto a proposta não for finalizada
+    #[view]
+    fn get_proposal_outcome(&self, proposal_id: u64) -> ProposalOutcome {
+        require!(
+            proposal_id < self.total_proposals().get(),
+            "Proposal does not exist"
+        );
+
+        let outcome_mapper = self.proposal_outcome(proposal_id);
+        if outcome_mapper.is_empty() {
+            self.compute_proposal_outcome(proposal_id)
+        } else {
+            outcome_mapper.get()
+        }
+    }
+
+    #[view]
+    fn get_finalization_bounty(&self) -> BigUint {
+        self.finalization_bounty().get()
+    }
+
+    #[view]
+    fn get_finalization_pool(&self) -> BigUint {
+        self.finalization_pool().get()
+    }
+
+    #[view]
+    fn get_anti_sniping_config(&self) -> AntiSnipingConfig {
+        self.load_anti_sniping_config()
+    }
+
+    #[view]
+    fn get_proposal_extension_count(&self, proposal_id: u64) -> usize {
+        require!(
+            proposal_id < self.total_proposals().get(),
+            "Proposal does not exist"
+        );
+        let root_id = self.proposal_parent(proposal_id).get().unwrap_or(proposal_id);
+        self.proposal_extension_count(root_id).get()
+    }
+
+    #[view]
+    fn get_ballot_type(&self, proposal_id: u64) -> BallotType {
+        require!(
+            proposal_id < self.total_proposals().get(),
+            "Proposal does not exist"
+        );
+        self.load_ballot_type(proposal_id)
+    }
+
+    #[view]
+    fn get_score_range(&self, proposal_id: u64) -> OptionalValue<ScoreRange> {
+        require!(
+            proposal_id < self.total_proposals().get(),
+            "Proposal does not exist"
+        );
+        self.load_score_range(proposal_id).into()
+    }
+
+    #[view]
+    fn get_proposal_seats(&self, proposal_id: u64) -> usize {
+        require!(
+            proposal_id < self.total_proposals().get(),
+            "Proposal does not exist"
+        );
+        self.load_seats(proposal_id)
+    }
+
+    // Eleitos em ordem de eleição; vazio até a finalização ou se a proposta for rejeitada
+    #[view]
+    fn get_elected_options(&self, proposal_id: u64) -> MultiValueEncoded<usize> {
+        require!(
+            proposal_id < self.total_proposals().get(),
+            "Proposal does not exist"
+        );
+
+        let mut result = MultiValueEncoded::new();
+        let outcome = match self.load_proposal_outcome(proposal_id) {
+            Some(outcome) if outcome.passed => outcome,
+            _ => return result,
+        };
+
+        if self.load_seats(proposal_id) == 1 {
+            result.push(outcome.winning_option);
+        } else {
+            for option in self.proposal_elected(proposal_id).iter() {
+                result.push(option);
+            }
+        }
+        result
+    }
+
+    // Contagem de primeiras preferências ativas em cada rodada já apurada
+    #[view]
+    fn get_ranked_rounds(&self, proposal_id: u64) -> MultiValueEncoded<ManagedVec<u64>> {
+        require!(
+            proposal_id < self.total_proposals().get(),
+            "Proposal does not exist"
+        );
+        self.ranked_rounds(proposal_id).iter().collect()
+    }
+
+    #[view]
+    fn get_ranked_count_state(
+        &self,
+        proposal_id: u64,
+    ) -> OptionalValue<RankedCountState<Self::Api>> {
+        require!(
+            proposal_id < self.total_proposals().get(),
+            "Proposal does not exist"
+        );
+
+        let mapper = self.ranked_count_state(proposal_id);
+        if mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(mapper.get())
+        }
+    }
+
+    // ============= VIEWS DE CONVICÇÃO =============
+
+    #[view]
+    fn get_governance_token(&self) -> OptionalValue<TokenIdentifier> {
+        if self.governance_token().is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(self.governance_token().get())
+        }
+    }
+
+    #[view]
+    fn get_conviction_config(&self) -> OptionalValue<ConvictionConfig> {
+        if self.conviction_config().is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(self.conviction_config().get())
+        }
+    }
+
+    #[view]
+    fn get_conviction_pool(&self) -> BigUint {
+        self.conviction_pool().get()
+    }
+
+    #[view]
+    fn get_conviction_total_staked(&self) -> BigUint {
+        self.conviction_total_staked().get()
+    }
+
+    #[view]
+    fn get_conviction(&self, proposal_id: u64) -> BigUint {
+        self.require_conviction_proposal(proposal_id);
+        let (conviction, _) = self.compute_conviction(proposal_id);
+        conviction
+    }
+
+    // Vazio quando o valor pedido excede a fração máxima do pool
+    #[view]
+    fn get_conviction_threshold(&self, proposal_id: u64) -> OptionalValue<BigUint> {
+        self.require_conviction_proposal(proposal_id);
+        self.conviction_threshold(proposal_id).into()
+    }
+
+    #[view]
+    fn get_conviction_staked(&self, proposal_id: u64) -> BigUint {
+        self.require_conviction_proposal(proposal_id);
+        self.conviction_staked(proposal_id).get()
+    }
+
+    #[view]
+    fn get_conviction_stake(&self, proposal_id: u64, staker: ManagedAddress) -> BigUint {
+        self.require_conviction_proposal(proposal_id);
+        self.conviction_stake(proposal_id, &staker).get()
+    }
+
+    // ============= VIEWS DE VOTE ESCROW =============
+
+    #[view]
+    fn get_vote_escrow_config(&self) -> OptionalValue<VoteEscrowConfig<Self::Api>> {
+        if self.vote_escrow_config().is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(self.vote_escrow_config().get())
+        }
+    }
+
+    #[view]
+    fn get_vote_escrow_lock(&self, user: ManagedAddress) -> OptionalValue<VoteEscrowLock<Self::Api>> {
+        let lock = self.load_vote_escrow_lock(&user);
+        if lock.amount == 0u32 {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(lock)
+        }
+    }
+
+    #[view]
+    fn get_voting_power(&self, user: ManagedAddress) -> BigUint {
+        let current_time = self.blockchain().get_block_timestamp();
+        self.voting_power_at(&user, current_time)
+    }
+
+    #[view]
+    fn get_voting_power_at(&self, user: ManagedAddress, timestamp: u64) -> BigUint {
+        self.voting_power_at(&user, timestamp)
+    }
+
+    #[view]
+    fn get_unlock_time(&self, user: ManagedAddress) -> u64 {
+        self.load_vote_escrow_lock(&user).unlock_time
+    }
+
+    #[view]
+    fn get_vote_escrow_total_locked(&self) -> BigUint {
+        self.vote_escrow_total_locked().get()
+    }
+
+    #[view]
+    fn get_proposal_voting_power(&self, proposal_id: u64) -> VotingPowerMode {
+        require!(
+            proposal_id < self.total_proposals().get(),
+            "Proposal does not exist"
+        );
+        self.load_voting_power(proposal_id)
+    }
+
+    #[view]
+    fn get_nft_gate(&self, proposal_id: u64) -> OptionalValue<NftGate<Self::Api>> {
+        require!(
+            proposal_id < self.total_proposals().get(),
+            "Proposal does not exist"
+        );
+        self.load_nft_gate(proposal_id).into()
+    }
+
+    #[view]
+    fn is_nft_nonce_used(&self, proposal_id: u64, nonce: u64) -> bool {
+        self.nft_nonce_used(proposal_id, nonce).get()
+    }
+
+    #[view]
+    fn get_nft_deposits(
+        &self,
+        proposal_id: u64,
+        voter: ManagedAddress,
+    ) -> MultiValueEncoded<EsdtTokenPayment> {
+        self.nft_deposits(proposal_id, &voter).iter().collect()
+    }
+
+    #[view]
+    fn get_proposal_snapshot(&self, proposal_id: u64) -> u64 {
+        require!(
+            proposal_id < self.total_proposals().get(),
+            "Proposal does not exist"
+        );
+        self.snapshot_time(proposal_id)
+    }
+
+    // ============= VIEWS DE TESOURARIA =============
+
+    #[view]
+    fn get_treasury_balance(&self, token_id: EgldOrEsdtTokenIdentifier, token_nonce: u64) -> BigUint {
+        self.treasury_balance(&token_id, token_nonce).get()
+    }
+
+    #[view]
+    fn get_treasury_balances(&self) -> MultiValueEncoded<EgldOrEsdtTokenPayment> {
+        let mut result = MultiValueEncoded::new();
+        for (token_id, token_nonce) in self.treasury_assets().iter() {
+            let amount = self.treasury_balance(&token_id, token_nonce).get();
+            result.push(EgldOrEsdtTokenPayment::new(token_id, token_nonce, amount));
+        }
+        result
+    }
+
+    // (quórum, limiar em bps); vazio enquanto o dono não configurar
+    #[view]
+    fn get_governance_action_rules(&self) -> OptionalValue<MultiValue2<u64, u64>> {
+        if self.governance_action_quorum().is_empty() {
+            return OptionalValue::None;
+        }
+        OptionalValue::Some(
+            (
+                self.governance_action_quorum().get(),
+                self.governance_action_threshold_bps().get(),
+            )
+                .into(),
+        )
+    }
+
+    #[view]
+    fn get_treasury_spend(&self, proposal_id: u64) -> OptionalValue<TreasurySpend<Self::Api>> {
+        require!(
+            proposal_id < self.total_proposals().get(),
+            "Proposal does not exist"
+        );
+        self.load_treasury_spend(proposal_id).into()
+    }
+
+    #[view]
+    fn is_treasury_spend_executed(&self, proposal_id: u64) -> bool {
+        self.treasury_spend_executed(proposal_id).get()
+    }
+
+    #[view]
+    fn get_spend_vesting(&self, proposal_id: u64) -> OptionalValue<VestingSchedule> {
+        let mapper = self.spend_vesting(proposal_id);
+        if mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(mapper.get())
+        }
+    }
+
+    #[view]
+    fn get_stream_count(&self) -> usize {
+        self.vesting_streams().len()
+    }
+
+    #[view]
+    fn get_stream(&self, stream_id: usize) -> VestingStream<Self::Api> {
+        require!(
+            stream_id > 0 && stream_id <= self.vesting_streams().len(),
+            "Stream does not exist"
+        );
+        self.vesting_streams().get(stream_id)
+    }
+
+    #[view]
+    fn get_stream_vested(&self, stream_id: usize) -> BigUint {
+        self.stream_vested(&self.get_stream(stream_id))
+    }
+
+    #[view]
+    fn get_stream_claimable(&self, stream_id: usize) -> BigUint {
+        self.stream_claimable(&self.get_stream(stream_id))
+    }
+
+    #[view]
+    fn get_streams_by_recipient(&self, recipient: ManagedAddress) -> MultiValueEncoded<usize> {
+        let mut result = MultiValueEncoded::new();
+        for stream_id in self.streams_by_recipient(&recipient).iter() {
+            result.push(stream_id);
+        }
+        result
+    }
+
+    // ============= VIEWS DE RECOMPENSA =============
+
+    #[view]
+    fn get_voting_reward_pool(&self, proposal_id: u64) -> OptionalValue<EgldOrEsdtTokenPayment> {
+        let mapper = self.voting_reward_pool(proposal_id);
+        if mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(mapper.get())
+        }
+    }
+
+    #[view]
+    fn get_voting_reward_distributed(&self, proposal_id: u64) -> BigUint {
+        self.voting_reward_distributed(proposal_id).get()
+    }
+
+    #[view]
+    fn get_voting_reward_deadline(&self, proposal_id: u64) -> u64 {
+        require!(
+            proposal_id < self.total_proposals().get(),
+            "Proposal does not exist"
+        );
+        self.voting_reward_deadline(proposal_id)
+    }
+
+    // Valor ainda resgatável pelo eleitor (zero se já resgatou ou antes da finalização)
+    #[view]
+    fn get_voting_reward(&self, proposal_id: u64, voter: ManagedAddress) -> BigUint {
+        if self.voting_reward_pool(proposal_id).is_empty()
+            || self.proposal_outcome(proposal_id).is_empty()
+            || self.voting_reward_claimed(proposal_id, &voter).get()
+        {
+            return BigUint::zero();
+        }
+        self.compute_voting_reward(proposal_id, &voter)
+    }
+
+    #[view]
+    fn has_claimed_voting_reward(&self, proposal_id: u64, voter: ManagedAddress) -> bool {
+        self.voting_reward_claimed(proposal_id, &voter).get()
+    }
+
+    #[view]
+    fn get_voting_reward_contribution(&self, proposal_id: u64, funder: ManagedAddress) -> BigUint {
+        self.voting_reward_contribution(proposal_id, &funder).get()
+    }
+
+    #[view]
+    fn get_default_bounty_rule(&self) -> BountyRule {
+        self.load_default_bounty_rule()
+    }
+
+    #[view]
+    fn get_creator_bounty(&self, proposal_id: u64) -> OptionalValue<EgldOrEsdtTokenPayment> {
+        let mapper = self.creator_bounty(proposal_id);
+        if mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(mapper.get())
+        }
+    }
+
+    #[view]
+    fn get_creator_bounty_rule(&self, proposal_id: u64) -> BountyRule {
+        require!(
+            proposal_id < self.total_proposals().get(),
+            "Proposal does not exist"
+        );
+        self.load_creator_bounty_rule(proposal_id)
+    }
+
+    // Saldo ainda não pago da recompensa
+    #[view]
+    fn get_creator_bounty_balance(&self, proposal_id: u64) -> BigUint {
+        let mapper = self.creator_bounty(proposal_id);
+        if mapper.is_empty() {
+            return BigUint::zero();
+        }
+        mapper.get().amount - self.creator_bounty_distributed(proposal_id).get()
+    }
+
+    #[view]
+    fn get_creator_bounty_share(&self, proposal_id: u64, voter: ManagedAddress) -> BigUint {
+        if self.creator_bounty(proposal_id).is_empty()
+            || self.proposal_outcome(proposal_id).is_empty()
+            || self.creator_bounty_claimed(proposal_id, &voter).get()
+        {
+            return BigUint::zero();
+        }
+        self.compute_creator_bounty_share(proposal_id, &voter)
+    }
+
+    #[view]
+    fn has_claimed_creator_bounty(&self, proposal_id: u64, voter: ManagedAddress) -> bool {
+        self.creator_bounty_claimed(proposal_id, &voter).get()
+    }
+
+    // ============= VIEWS DE MERCADO DE PREVISÃO =============
+
+    #[view]
+    fn get_prediction_fee(&self) -> u64 {
+        self.prediction_fee_bps().get()
+    }
+
+    #[view]
+    fn get_prediction_market(&self, proposal_id: u64) -> OptionalValue<PredictionMarket<Self::Api>> {
+        let mapper = self.prediction_market(proposal_id);
+        if mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(mapper.get())
+        }
+    }
+
+    // Total apostado em cada opção, na ordem das opções
+    #[view]
+    fn get_prediction_pools(&self, proposal_id: u64) -> MultiValueEncoded<BigUint> {
+        require!(
+            proposal_id < self.total_proposals().get(),
+            "Proposal does not exist"
+        );
+        let mut result = MultiValueEncoded::new();
+        for option in 0..self.proposal_options(proposal_id).len() {
+            result.push(self.prediction_option_staked(proposal_id, option).get());
+        }
+        result
+    }
+
+    #[view]
+    fn get_prediction_total_staked(&self, proposal_id: u64) -> BigUint {
+        self.prediction_total_staked(proposal_id).get()
+    }
+
+    #[view]
+    fn get_prediction_position(
+        &self,
+        proposal_id: u64,
+        staker: ManagedAddress,
+    ) -> OptionalValue<PredictionPosition<Self::Api>> {
+        let mapper = self.prediction_position(proposal_id, &staker);
+        if mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(mapper.get())
+        }
+    }
+
+    // Opção vencedora após a liquidação; vazio enquanto aberto ou quando as apostas são devolvidas
+    #[view]
+    fn get_prediction_winning_option(&self, proposal_id: u64) -> OptionalValue<usize> {
+        self.prediction_winning_option(proposal_id).get().into()
+    }
+
+    #[view]
+    fn get_prediction_payout(&self, proposal_id: u64, staker: ManagedAddress) -> BigUint {
+        let finalized = !self.proposal_outcome(proposal_id).is_empty()
+            || self.compute_proposal_status(proposal_id) == ProposalStatus::Cancelled;
+        if self.prediction_market(proposal_id).is_empty()
+            || !finalized
+            || self.prediction_claimed(proposal_id, &staker).get()
+        {
+            return BigUint::zero();
+        }
+        self.compute_prediction_payout(proposal_id, &staker)
+    }
+
+    // ============= VIEWS DE UPGRADE =============
+
+    #[view]
+    fn get_code_upgrade(&self, proposal_id: u64) -> OptionalValue<CodeUpgrade<Self::Api>> {
+        let mapper = self.code_upgrade(proposal_id);
+        if mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(mapper.get())
+        }
+    }
+
+    // Momento a partir do qual o upgrade aprovado pode ser executado
+    #[view]
+    fn get_code_upgrade_eta(&self, proposal_id: u64) -> u64 {
+        require!(
+            proposal_id < self.total_proposals().get(),
+            "Proposal does not exist"
+        );
+        self.code_upgrade_eta(proposal_id)
+    }
+
+    #[view]
+    fn is_code_upgrade_executed(&self, proposal_id: u64) -> bool {
+        self.code_upgrade_executed(proposal_id).get()
+    }
+
+    // ============= VIEWS DE MIGRAÇÃO =============
+
+    #[view]
+    fn get_storage_version(&self) -> u32 {
+        self.storage_version().get()
+    }
+
+    // (versão do layout, propostas convertidas, total de propostas)
+    #[view]
+    fn get_migration_progress(&self) -> MultiValue3<u32, u64, u64> {
+        let version = self.storage_version().get();
+        let total = self.total_proposals().get();
+        let migrated = if version >= CURRENT_STORAGE_VERSION {
+            total
+        } else {
+            self.migration_cursor().get()
+        };
+        (version, migrated, total).into()
+    }
+
+    // ============= VIEWS DE CATEGORIA =============
+
+    #[view]
+    fn get_categories(&self) -> MultiValueEncoded<Category<Self::Api>> {
+        self.categories().iter().collect()
+    }
+
+    #[view]
+    fn get_category(&self, category_id: usize) -> Category<Self::Api> {
+        self.require_category_exists(category_id);
+        self.categories().get(category_id)
+    }
+
+    #[view]
+    fn get_proposals_by_category(
+        &self,
+        category_id: usize,
+        from: usize,
+        count: usize,
+    ) -> MultiValueEncoded<Proposal<Self::Api>> {
+        let mapper = self.proposals_by_category(category_id);
+        let (start, end) = self.page_bounds(from, count, mapper.len());
+
+        let mut result = MultiValueEncoded::new();
+        for index in start..end {
+            result.push(self.build_proposal(mapper.get_by_index(index + 1)));
+        }
+        result
+    }
+
+    #[view]
+    fn get_category_proposal_count(&self, category_id: usize) -> usize {
+        self.proposals_by_category(category_id).len()
+    }
+
+    #[view]
+    fn get_proposal_tags(&self, proposal_id: u64) -> MultiValueEncoded<ManagedBuffer> {
+        require!(
+            proposal_id < self.total_proposals().get(),
+            "Proposal does not exist"
+        );
+        self.proposal_tags(proposal_id).get().into()
+    }
+
+    // ============= VIEWS DE ESPAÇOS =============
+
+    #[view]
+    fn get_space_count(&self) -> usize {
+        self.space_names().len()
+    }
+
+    #[view]
+    fn get_space(&self, space_id: usize) -> Space<Self::Api> {
+        self.require_space_exists(space_id);
+        Space {
+            id: space_id,
+            name: self.space_names().get(space_id),
+            config: self.space_config(space_id).get(),
+            admin_count: self.space_admins(space_id).len(),
+            voter_count: self.space_voters(space_id).len(),
+            total_proposals: self.space_proposals(space_id).len() as u64,
+            total_votes: self.space_total_votes(space_id).get(),
+        }
+    }
+
+    #[view]
+    fn get_space_admins(&self, space_id: usize) -> MultiValueEncoded<ManagedAddress> {
+        self.require_space_exists(space_id);
+        self.space_admins(space_id).iter().collect()
+    }
+
+    #[view]
+    fn is_space_admin(&self, space_id: usize, address: ManagedAddress) -> bool {
+        self.space_admins(space_id).contains(&address)
+    }
+
+    #[view]
+    fn is_space_voter(&self, space_id: usize, address: ManagedAddress) -> bool {
+        self.space_voters(space_id).contains(&address)
+    }
+
+    #[view]
+    fn get_space_voters(
+        &self,
+        space_id: usize,
+        from: usize,
+        count: usize,
+    ) -> MultiValueEncoded<ManagedAddress> {
+        self.require_space_exists(space_id);
+        let mapper = self.space_voters(space_id);
+        let (start, end) = self.page_bounds(from, count, mapper.len());
+
+        let mut result = MultiValueEncoded::new();
+        for index in start..end {
+            result.push(mapper.get_by_index(index + 1));
+        }
+        result
+    }
+
+    #[view]
+    fn get_space_config(&self, space_id: usize) -> SpaceConfig {
+        self.require_space_exists(space_id);
+        self.space_config(space_id).get()
+    }
+
+    #[view]
+    fn get_space_total_proposals(&self, space_id: usize) -> u64 {
+        self.require_space_exists(space_id);
+        self.space_proposals(space_id).len() as u64
+    }
+
+    #[view]
+    fn get_space_total_votes(&self, space_id: usize) -> u64 {
+        self.require_space_exists(space_id);
+        self.space_total_votes(space_id).get()
+    }
+
+    // O campo `id` da proposta retornada é o id global
+    #[view]
+    fn get_space_proposal(&self, space_id: usize, local_id: u64) -> Proposal<Self::Api> {
+        let proposal_id = self.resolve_space_proposal(space_id, local_id);
+        self.build_proposal(proposal_id)
+    }
+
+    #[view]
+    fn get_space_proposals(
+        &self,
+        space_id: usize,
+        from: usize,
+        count: usize,
+    ) -> MultiValueEncoded<Proposal<Self::Api>> {
+        self.require_space_exists(space_id);
+        let mapper = self.space_proposals(space_id);
+        let (start, end) = self.page_bounds(from, count, mapper.len());
+
+        let mut result = MultiValueEncoded::new();
+        for index in start..end {
+            result.push(self.build_proposal(mapper.get(index + 1)));
+        }
+        result
+    }
+
+    #[view]
+    fn get_space_proposal_id(&self, space_id: usize, local_id: u64) -> u64 {
+        self.resolve_space_proposal(space_id, local_id)
+    }
+
+    // 0 para propostas do espaço padrão
+    #[view]
+    fn get_proposal_space(&self, proposal_id: u64) -> usize {
+        require!(
+            proposal_id < self.total_proposals().get(),
+            "Proposal does not exist"
+        );
+        self.proposal_space(proposal_id).get()
+    }
+
+    // ============= VIEWS POR USUÁRIO =============
+
+    #[view]
+    fn get_proposals_by_creator(
+        &self,
+        creator: ManagedAddress,
+        from: usize,
+        count: usize,
+    ) -> MultiValueEncoded<Proposal<Self::Api>> {
+        let mapper = self.proposals_by_creator(&creator);
+        let (start, end) = self.page_bounds(from, count, mapper.len());
+
+        let mut result = MultiValueEncoded::new();
+        for index in start..end {
+            // VecMapper é indexado a partir de 1
+            result.push(self.build_proposal(mapper.get(index + 1)));
+        }
+        result
+    }
+
+    #[view]
+    fn get_creator_proposal_count(&self, creator: ManagedAddress) -> usize {
+        self.proposals_by_creator(&creator).len()
+    }
+
+    #[view]
+    fn get_votes_by_voter(
+        &self,
+        voter: ManagedAddress,
+        from: usize,
+        count: usize,
+    ) -> MultiValueEncoded<VoteRecord> {
+        let mapper = self.votes_by_voter(&voter);
+        let (start, end) = self.page_bounds(from, count, mapper.len());
+
+        let mut result = MultiValueEncoded::new();
+        for index in start..end {
+            result.push(mapper.get(index + 1));
+        }
+        result
+    }
+
+    #[view]
+    fn get_voter_vote_count(&self, voter: ManagedAddress) -> usize {
+        self.votes_by_voter(&voter).len()
+    }
+
+    // ============= FUNCÕES AUXILIARES =============
+
+    // Lê o layout atual ou, para propostas ainda não migradas, o layout legado
+    fn load_proposal_header(&self, proposal_id: u64) -> ProposalHeader<Self::Api> {
+        let mapper = self.proposal_header(proposal_id);
+        if !mapper.is_empty() {
+            return mapper.get();
+        }
+        ProposalHeader {
+            title: self.proposal_title(proposal_id).get(),
+            description: self.proposal_description(proposal_id).get(),
+            creator: self.proposal_creator(proposal_id).get(),
+            duration: self.proposal_duration(proposal_id).get(),
+        }
+    }
+
+    // Propostas do layout antigo não passaram pelos índices criados depois:
+    // eram de escolha única, sem categoria e publicadas na criação
+    fn migrate_legacy_proposal(&self, proposal_id: u64) {
+        let header = self.load_proposal_header(proposal_id);
+        self.save_proposal_header(proposal_id, &header);
+
+        self.proposals_by_creator(&header.creator).push(&proposal_id);
+        self.proposals_by_category(UNCATEGORIZED).insert(proposal_id);
+        if self.proposal_active(proposal_id).get() {
+            self.active_proposals().insert(proposal_id);
+        }
+        self.proposal_option_votes(proposal_id, 0)
+            .set(self.proposal_vote_count(proposal_id).get());
+
+        // O início não era gravado; sem a duração fica no próprio prazo
+        let deadline = self.proposal_deadline(proposal_id).get();
+        self.proposal_start(proposal_id)
+            .set(deadline.saturating_sub(header.duration));
+    }
+
+    // Toda escrita usa o layout atual e descarta as entradas legadas
+    fn save_proposal_header(&self, proposal_id: u64, header: &ProposalHeader<Self::Api>) {
+        if self.storage_version().get() < CURRENT_STORAGE_VERSION
+            && self.proposal_header(proposal_id).is_empty()
+        {
+            self.proposal_title(proposal_id).clear();
+            self.proposal_description(proposal_id).clear();
+            self.proposal_creator(proposal_id).clear();
+            self.proposal_duration(proposal_id).clear();
+        }
+        self.proposal_header(proposal_id).set(header);
+    }
+
+    fn require_owner(&self) {
+        let caller = self.blockchain().get_caller();
+        require!(caller == self.owner().get(), "Only owner can call this function");
+    }
+
+    // A pausa geral continua bloqueando tudo; as pausas por operação somam-se a ela
+    fn require_not_paused(&self, scope: PauseScope) {
+        require!(!self.is_paused().get(), "Contract is paused");
+        require!(!self.is_operation_paused(scope), "Operation is paused");
+    }
+
+    fn require_pauser(&self) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            caller == self.owner().get() || self.pausers().contains(&caller),
+            "Only pauser can call this function"
+        );
+    }
+
+    fn load_operation_pause(&self, scope: PauseScope) -> OperationPause {
+        let paused_until = self.operation_paused_until(scope).get();
+        if self.blockchain().get_block_timestamp() >= paused_until {
+            return OperationPause {
+                paused: false,
+                unpause_at: None,
+            };
+        }
+
+        OperationPause {
+            paused: true,
+            unpause_at: if paused_until == INDEFINITE_PAUSE {
+                None
+            } else {
+                Some(paused_until)
+            },
+        }
+    }
+
+    fn require_valid_proposal_content(
+        &self,
+        title: &ManagedBuffer,
+        description: &ManagedBuffer,
+        options: &ManagedVec<ManagedBuffer>,
+    ) {
+        require!(!title.is_empty(), "Title cannot be empty");
+        require!(!description.is_empty(), "Description cannot be empty");
+        require!(title.len() <= MAX_TITLE_LENGTH, "Title too long");
+        require!(description.len() <= MAX_DESCRIPTION_LENGTH, "Description too long");
+
+        // Sem opções, a proposta é de apoio simples (escolha única 0)
+        require!(
+            options.is_empty() || options.len() >= 2,
+            "At least two options required"
+        );
+        require!(options.len() <= MAX_OPTIONS, "Too many options");
+        for option in options.iter() {
+            require!(!option.is_empty(), "Option cannot be empty");
+            require!(option.len() <= MAX_OPTION_LENGTH, "Option too long");
+        }
+    }
+
+    fn register_proposal(
+        &self,
+        title: ManagedBuffer,
+        description: ManagedBuffer,
+        duration: u64,
+        options: ManagedVec<ManagedBuffer>,
+    ) -> u64 {
+        let caller = self.blockchain().get_caller();
+
+        // Validações básicas
+        self.require_valid_proposal_content(&title, &description, &options);
+
+        // Limitar propostas por usuário
+        let user_proposals = self.user_proposal_count(&caller).get();
+        require!(
+            user_proposals < MAX_PROPOSALS_PER_USER,
+            "Maximum proposals per user exceeded"
+        );
+
+        let proposal_id = self.store_proposal(&caller, title, description, duration, options);
+
+        // Atualizar contadores
+        self.user_proposal_count(&caller).update(|x| *x += 1);
+        self.proposals_by_creator(&caller).push(&proposal_id);
+        proposal_id
+    }
+
+    // Propostas de espaços passam só por aqui: ficam fora do índice por criador e
+    // das categorias do espaço padrão; o limite por usuário é contado por espaço
+    fn store_proposal(
+        &self,
+        creator: &ManagedAddress,
+        title: ManagedBuffer,
+        description: ManagedBuffer,
+        duration: u64,
+        options: ManagedVec<ManagedBuffer>,
+    ) -> u64 {
+        let proposal_id = self.total_proposals().get();
+
+        self.save_proposal_header(
+            proposal_id,
+            &ProposalHeader {
+                title,
+                description,
+                creator: creator.clone(),
+                duration,
+            },
+        );
+        self.proposal_vote_count(proposal_id).set(0u64);
+        self.proposal_active(proposal_id).set(true);
+        for option in options.iter() {
+            self.proposal_options(proposal_id).push(&*option);
+        }
+        self.total_proposals().update(|x| *x += 1);
+
+        self.proposal_created_event(proposal_id, creator);
+        proposal_id
+    }
+
+    // O prazo só começa a contar quando a votação é aberta
+    fn open_voting(&self, proposal_id: u64) {
+        let current_time = self.blockchain().get_block_timestamp();
+        let duration = self.load_proposal_header(proposal_id).duration;
+
+        self.proposal_start(proposal_id).set(current_time);
+        self.proposal_deadline(proposal_id).set(current_time + duration);
+        self.active_proposals().insert(proposal_id);
+
+        // Só propostas publicadas entram no índice; como a categoria não muda depois
+        // da publicação, nada é removido e as páginas ficam estáveis
+        if self.proposal_space(proposal_id).is_empty() {
+            self.proposals_by_category(self.proposal_category(proposal_id).get())
+                .insert(proposal_id);
+        }
+    }
+
+    fn assign_category(&self, proposal_id: u64, category_id: usize, quorum: u64, threshold_bps: u64) {
+        self.proposal_category(proposal_id).set(category_id);
+        self.proposal_quorum(proposal_id).set(quorum);
+        self.proposal_threshold_bps(proposal_id).set(threshold_bps);
+    }
+
+    // Emendas em aberto caem junto com a proposta original
+    fn cancel_with_amendments(&self, proposal_id: u64, cancelled_by: &ManagedAddress) {
+        self.deactivate_proposal(proposal_id, cancelled_by);
+        for amendment_id in self.proposal_amendments(proposal_id).iter() {
+            if self.proposal_active(amendment_id).get() {
+                self.deactivate_proposal(amendment_id, cancelled_by);
+            }
+        }
+    }
+
+    fn deactivate_proposal(&self, proposal_id: u64, cancelled_by: &ManagedAddress) {
+        self.proposal_active(proposal_id).set(false);
+        self.active_proposals().swap_remove(&proposal_id);
+        self.proposal_cancelled_event(proposal_id, cancelled_by);
+    }
+
+    // Em caso de empate lidera a opção de menor índice
+    fn leading_option(&self, proposal_id: u64) -> (usize, u64) {
+        let options_count = core::cmp::max(self.proposal_options(proposal_id).len(), 1);
+
+        let mut leading_option = 0usize;
+        let mut leading_votes = 0u64;
+        for option in 0..options_count {
+            let votes = self.proposal_option_votes(proposal_id, option).get();
+            if votes > leading_votes {
+                leading_option = option;
+                leading_votes = votes;
+            }
+        }
+        (leading_option, leading_votes)
+    }
+
+    // Resultado da própria proposta, sem considerar emendas
+    // Em votações ponderadas os totais são medidos em peso, não em eleitores
+    fn compute_own_outcome(&self, proposal_id: u64) -> ProposalOutcome {
+        let total_votes = self.load_total_weight(proposal_id);
+        let (winning_option, winning_votes) = self.leading_option(proposal_id);
+
+        let quorum_reached = total_votes > 0 && total_votes >= self.proposal_quorum(proposal_id).get();
+        let threshold_base = match self.load_score_range(proposal_id) {
+            Some(range) => total_votes * range.max as u64,
+            None => total_votes,
+        };
+        let threshold_reached = total_votes > 0
+            && winning_votes * MAX_BPS >= threshold_base * self.proposal_threshold_bps(proposal_id).get();
+
+        ProposalOutcome {
+            winning_option,
+            winning_votes,
+            total_votes,
+            quorum_reached,
+            threshold_reached,
+            passed: quorum_reached && threshold_reached,
+            adopted_amendment: None,
+        }
+    }
+
+    // Uma emenda aprovada substitui a proposta original quando sua opção vencedora
+    // recebe mais votos do que a vencedora da original (ou quando a original falha)
+    fn compute_proposal_outcome(&self, proposal_id: u64) -> ProposalOutcome {
+        let mut outcome = self.compute_own_outcome(proposal_id);
+
+        let mut best: Option<(u64, ProposalOutcome)> = None;
+        // Emendas já finalizadas continuam na disputa; só as canceladas ficam de fora
+        for amendment_id in self.proposal_amendments(proposal_id).iter() {
+            if !self.proposal_active(amendment_id).get() && self.proposal_outcome(amendment_id).is_empty() {
+                continue;
+            }
+
+            let amendment_outcome = self.compute_own_outcome(amendment_id);
+            if !amendment_outcome.passed {
+                continue;
+            }
+
+            let is_better = match &best {
+                Some((_, current)) => amendment_outcome.winning_votes > current.winning_votes,
+                None => true,
+            };
+            if is_better {
+                best = Some((amendment_id, amendment_outcome));
+            }
+        }
+
+        if let Some((amendment_id, amendment_outcome)) = best {
+            if !outcome.passed || amendment_outcome.winning_votes > outcome.winning_votes {
+                outcome.winning_option = amendment_outcome.winning_option;
+                outcome.passed = true;
+                outcome.adopted_amendment = Some(amendment_id);
+            }
+        }
+        outcome
+    }
+
+    fn load_ballot_type(&self, proposal_id: u64) -> BallotType {
+        let mapper = self.proposal_ballot_type(proposal_id);
+        if mapper.is_empty() {
+            BallotType::SingleChoice
+        } else {
+            mapper.get()
+        }
+    }
+
+    fn load_seats(&self, proposal_id: u64) -> usize {
+        core::cmp::max(self.proposal_seats(proposal_id).get(), 1)
+    }
+
+    fn load_score_range(&self, proposal_id: u64) -> Option<ScoreRange> {
+        if self.load_ballot_type(proposal_id) != BallotType::Score {
+            return None;
+        }
+
+        let mapper = self.proposal_score_range(proposal_id);
+        if mapper.is_empty() {
+            None
+        } else {
+            Some(mapper.get())
+        }
+    }
+
+    fn record_single_choice(&self, proposal_id: u64, ballot: &ManagedVec<usize>, weight: u64) -> usize {
+        require!(ballot.len() <= 1, "Invalid ballot");
+
+        let choice = if ballot.is_empty() { 0 } else { ballot.get(0) };
+        let options_count = self.proposal_options(proposal_id).len();
+        if options_count == 0 {
+            require!(choice == 0, "Invalid option");
+        } else {
+            require!(choice < options_count, "Invalid option");
+        }
+
+        self.proposal_option_votes(proposal_id, choice).update(|x| *x += weight);
+        choice
+    }
+
+    // Cada cédula ranqueada ocupa um byte por preferência (MAX_OPTIONS cabe em u8).
+    // Os votos por opção contam apenas as primeiras preferências.
+    fn record_ranked_ballot(&self, proposal_id: u64, ballot: &ManagedVec<usize>) -> usize {
+        let options_count = self.proposal_options(proposal_id).len();
+        require!(
+            !ballot.is_empty() && ballot.len() <= options_count,
+            "Invalid ballot"
+        );
+
+        let mut seen = 0u32;
+        let mut encoded = ManagedBuffer::new();
+        for option in ballot.iter() {
+            require!(option < options_count, "Invalid option");
+            require!(seen & (1u32 << option) == 0, "Duplicate option in ballot");
+            seen |= 1u32 << option;
+            encoded.append_bytes(&[option as u8]);
+        }
+        self.ranked_ballots(proposal_id).push(&encoded);
+
+        let first_choice = ballot.get(0);
+        self.proposal_option_votes(proposal_id, first_choice).update(|x| *x += 1);
+        first_choice
+    }
+
+    // O voto registrado guarda o próprio bitmap como escolha
+    fn record_approval_ballot(&self, proposal_id: u64, ballot: &ManagedVec<usize>, weight: u64) -> usize {
+        require!(ballot.len() == 1, "Invalid ballot");
+
+        let approved = ballot.get(0);
+        let options_count = self.proposal_options(proposal_id).len();
+        require!(approved != 0, "Invalid ballot");
+        require!(approved >> options_count == 0, "Invalid option");
+
+        for option in 0..options_count {
+            if approved & (1usize << option) != 0 {
+                self.proposal_option_votes(proposal_id, option).update(|x| *x += weight);
+            }
+        }
+        approved
+    }
+
+    // O voto registrado guarda a opção de maior nota (empate: menor índice)
+    fn record_score_ballot(&self, proposal_id: u64, ballot: &ManagedVec<usize>) -> usize {
+        let options_count = self.proposal_options(proposal_id).len();
+        require!(ballot.len() == options_count, "Invalid ballot");
+
+        let range = self.proposal_score_range(proposal_id).get();
+        let mut top_option = 0usize;
+        let mut top_score = 0usize;
+        for (option, score) in ballot.iter().enumerate() {
+            require!((range.min..=range.max).contains(&score), "Score out of range");
+            if option == 0 || score > top_score {
+                top_option = option;
+                top_score = score;
+            }
+            self.proposal_option_votes(proposal_id, option)
+                .update(|x| *x += score as u64);
+        }
+        top_option
+    }
+
+    // Segundo turno instantâneo: a cada rodada cada cédula conta para sua preferência
+    // mais alta ainda não eliminada. Com uma vaga vence quem tiver maioria das cédulas
+    // não esgotadas; com várias vagas (STV) é eleito quem atingir a cota Droop e o
+    // excedente segue para as próximas preferências com peso reduzido. Sem eleito na
+    // rodada, a opção com menos votos é eliminada (empate: maior índice).
+    // Se o gás acabar no meio da rodada o progresso é salvo e `None` é retornado.
+    fn advance_ranked_count(&self, proposal_id: u64) -> Option<ProposalOutcome> {
+        let options_count = self.proposal_options(proposal_id).len();
+        let seats = self.load_seats(proposal_id);
+        let ballots = self.ranked_ballots(proposal_id);
+        let quota = (ballots.len() as u64 / (seats as u64 + 1) + 1) * VOTE_WEIGHT;
+        let mut state = self.load_ranked_count_state(proposal_id, options_count);
+
+        let mut counts = [0u64; MAX_OPTIONS];
+        for (option, votes) in state.counts.iter().enumerate() {
+            counts[option] = votes;
+        }
+        let mut transfer_factors = [VOTE_WEIGHT; MAX_OPTIONS];
+        for (option, factor) in state.transfer_factors.iter().enumerate() {
+            transfer_factors[option] = factor;
+        }
+
+        loop {
+            while state.next_ballot <= ballots.len() {
+                if self.blockchain().get_gas_left() < MIN_GAS_FOR_COUNT_STEP {
+                    state.counts = counts[..options_count].iter().copied().collect();
+                    state.transfer_factors = transfer_factors[..options_count].iter().copied().collect();
+                    self.ranked_count_state(proposal_id).set(&state);
+                    self.ranked_count_progress_event(proposal_id, state.round, state.next_ballot);
+                    return None;
+                }
+
+                let ballot = ballots.get(state.next_ballot);
+                let mut preferences = [0u8; MAX_OPTIONS];
+                let preferences = ballot.load_to_byte_array(&mut preferences);
+
+                let mut weight = VOTE_WEIGHT;
+                let mut counted = false;
+                for option in preferences.iter().map(|option| *option as usize) {
+                    if state.eliminated & (1u32 << option) != 0 {
+                        continue;
+                    }
+                    if state.elected & (1u32 << option) != 0 {
+                        weight = weight * transfer_factors[option] / VOTE_WEIGHT;
+                        continue;
+                    }
+                    counts[option] += weight;
+                    counted = true;
+                    break;
+                }
+                if !counted {
+                    state.exhausted += 1;
+                }
+                state.next_ballot += 1;
+            }
+
+            // As rodadas expõem votos inteiros; frações de excedente são truncadas
+            let round_counts: ManagedVec<u64> = counts[..options_count]
+                .iter()
+                .map(|votes| votes / VOTE_WEIGHT)
+                .collect();
+            self.ranked_rounds(proposal_id).push(&round_counts);
+
+            let mut continuing = 0u64;
+            let mut remaining = 0usize;
+            let mut leader = 0usize;
+            let mut leader_votes = 0u64;
+            let mut loser = 0usize;
+            let mut loser_votes = u64::MAX;
+            for (option, &votes) in counts[..options_count].iter().enumerate() {
+                if (state.eliminated | state.elected) & (1u32 << option) != 0 {
+                    continue;
+                }
+                continuing += votes;
+                remaining += 1;
+                if remaining == 1 || votes > leader_votes {
+                    leader = option;
+                    leader_votes = votes;
+                }
+                if votes <= loser_votes {
+                    loser = option;
+                    loser_votes = votes;
+                }
+            }
+
+            if seats == 1 {
+                if continuing == 0 || leader_votes * 2 > continuing || remaining <= 1 {
+                    self.ranked_count_state(proposal_id).clear();
+                    self.ranked_count_completed_event(proposal_id, leader, state.round);
+                    return Some(self.build_ranked_outcome(
+                        proposal_id,
+                        leader,
+                        leader_votes / VOTE_WEIGHT,
+                        continuing / VOTE_WEIGHT,
+                    ));
+                }
+            } else {
+                let seats_left = seats - state.elected.count_ones() as usize;
+                if remaining <= seats_left {
+                    self.elect_remaining_options(proposal_id, &counts[..options_count], &state);
+                    self.ranked_count_state(proposal_id).clear();
+                    self.ranked_count_completed_event(proposal_id, leader, state.round);
+                    return Some(self.compute_multi_winner_outcome(proposal_id));
+                }
+
+                if leader_votes >= quota {
+                    // Em u128: excedente × VOTE_WEIGHT passa de u64 com mais de ~18 cédulas
+                    transfer_factors[leader] = ((leader_votes - quota) as u128 * VOTE_WEIGHT as u128
+                        / leader_votes as u128) as u64;
+                    state.elected |= 1u32 << leader;
+                    self.proposal_elected(proposal_id).push(&leader);
+
+                    if seats_left == 1 {
+                        self.ranked_count_state(proposal_id).clear();
+                        self.ranked_count_completed_event(proposal_id, leader, state.round);
+                        return Some(self.compute_multi_winner_outcome(proposal_id));
+                    }
+                    self.start_next_round(&mut state, &mut counts);
+                    continue;
+                }
+            }
+
+            state.eliminated |= 1u32 << loser;
+            self.start_next_round(&mut state, &mut counts);
+        }
+    }
+
+    fn start_next_round(&self, state: &mut RankedCountState<Self::Api>, counts: &mut [u64; MAX_OPTIONS]) {
+        state.round += 1;
+        state.next_ballot = 1;
+        state.exhausted = 0;
+        *counts = [0u64; MAX_OPTIONS];
+    }
+
+    // Opções ainda em disputa preenchem as vagas restantes, da mais votada à menos votada
+    fn elect_remaining_options(
+        &self,
+        proposal_id: u64,
+        counts: &[u64],
+        state: &RankedCountState<Self::Api>,
+    ) {
+        let mut taken = state.eliminated | state.elected;
+        loop {
+            let mut best: Option<(usize, u64)> = None;
+            for (option, &votes) in counts.iter().enumerate() {
+                if taken & (1u32 << option) != 0 {
+                    continue;
+                }
+                let is_better = match best {
+                    Some((_, best_votes)) => votes > best_votes,
+                    None => true,
+                };
+                if is_better {
+                    best = Some((option, votes));
+                }
+            }
+
+            match best {
+                Some((option, _)) => {
+                    taken |= 1u32 << option;
+                    self.proposal_elected(proposal_id).push(&option);
+                },
+                None => return,
+            }
+        }
+    }
+
+    fn load_ranked_count_state(
+        &self,
+        proposal_id: u64,
+        options_count: usize,
+    ) -> RankedCountState<Self::Api> {
+        let mapper = self.ranked_count_state(proposal_id);
+        if !mapper.is_empty() {
+            return mapper.get();
+        }
+
+        let mut counts = ManagedVec::new();
+        let mut transfer_factors = ManagedVec::new();
+        for _ in 0..options_count {
+            counts.push(0u64);
+            transfer_factors.push(VOTE_WEIGHT);
+        }
+        RankedCountState {
+            round: 0,
+            next_ballot: 1,
+            eliminated: 0,
+            elected: 0,
+            exhausted: 0,
+            counts,
+            transfer_factors,
+        }
+    }
+
+    // Pluralidade multivagas: as opções com maior contagem, em ordem decrescente
+    // (empate: menor índice)
+    fn elect_top_options(&self, proposal_id: u64) {
+        let option_votes = self.build_option_votes(proposal_id);
+        let mut counts = [0u64; MAX_OPTIONS];
+        for (option, votes) in option_votes.iter().enumerate() {
+            counts[option] = votes;
+        }
+
+        let mut elected = 0u32;
+        for _ in 0..self.load_seats(proposal_id) {
+            let mut best: Option<(usize, u64)> = None;
+            for (option, &votes) in counts[..option_votes.len()].iter().enumerate() {
+                if elected & (1u32 << option) != 0 {
+                    continue;
+                }
+                let is_better = match best {
+                    Some((_, best_votes)) => votes > best_votes,
+                    None => true,
+                };
+                if is_better {
+                    best = Some((option, votes));
+                }
+            }
+
+            if let Some((option, _)) = best {
+                elected |= 1u32 << option;
+                self.proposal_elected(proposal_id).push(&option);
+            }
+        }
+    }
+
+    // O primeiro eleito ocupa o lugar de opção vencedora no resultado
+    fn compute_multi_winner_outcome(&self, proposal_id: u64) -> ProposalOutcome {
+        let mut outcome = self.compute_own_outcome(proposal_id);
+        let first_elected = self.proposal_elected(proposal_id).get(1);
+        outcome.winning_option = first_elected;
+        outcome.winning_votes = self.proposal_option_votes(proposal_id, first_elected).get();
+        outcome
+    }
+
+    // O limiar é medido contra as cédulas ainda válidas na rodada final
+    fn build_ranked_outcome(
+        &self,
+        proposal_id: u64,
+        winning_option: usize,
+        winning_votes: u64,
+        continuing: u64,
+    ) -> ProposalOutcome {
+        let total_votes = self.proposal_vote_count(proposal_id).get();
+
+        let quorum_reached = total_votes > 0 && total_votes >= self.proposal_quorum(proposal_id).get();
+        let threshold_reached = continuing > 0
+            && winning_votes * MAX_BPS >= continuing * self.proposal_threshold_bps(proposal_id).get();
+
+        ProposalOutcome {
+            winning_option,
+            winning_votes,
+            total_votes,
+            quorum_reached,
+            threshold_reached,
+            passed: quorum_reached && threshold_reached,
+            adopted_amendment: None,
+        }
+    }
+
+    // Prorroga o prazo se a liderança mudou dentro da janela final
+    fn apply_anti_sniping(&self, proposal_id: u64, leader_before: usize, voter: &ManagedAddress) {
+        let config = self.load_anti_sniping_config();
+        if config.window == 0 {
+            return;
+        }
+
+        let (leader_after, _) = self.leading_option(proposal_id);
+        if leader_after == leader_before {
+            return;
+        }
+
+        let current_time = self.blockchain().get_block_timestamp();
+        let deadline = self.proposal_deadline(proposal_id).get();
+        if deadline - current_time > config.window {
+            return;
+        }
+
+        let root_id = self.proposal_parent(proposal_id).get().unwrap_or(proposal_id);
+        if self.proposal_extension_count(root_id).get() >= config.max_extensions {
+            return;
+        }
+
+        self.proposal_extension_count(root_id).update(|x| *x += 1);
+        self.extend_proposal_family(
+            proposal_id,
+            config.extension,
+            voter,
+            &ManagedBuffer::from(ANTI_SNIPING_REASON),
+        );
+    }
+
+    fn load_anti_sniping_config(&self) -> AntiSnipingConfig {
+        let mapper = self.anti_sniping_config();
+        if mapper.is_empty() {
+            AntiSnipingConfig::default()
+        } else {
+            mapper.get()
+        }
+    }
+
+    // Proposta original e emendas compartilham o mesmo prazo
+    fn extend_proposal_family(
+        &self,
+        proposal_id: u64,
+        extension: u64,
+        extended_by: &ManagedAddress,
+        reason: &ManagedBuffer,
+    ) {
+        let root_id = self.proposal_parent(proposal_id).get().unwrap_or(proposal_id);
+        let new_deadline = self.proposal_deadline(root_id).get() + extension;
+
+        self.proposal_deadline(root_id).set(new_deadline);
+        self.deadline_extended_event(root_id, new_deadline, extended_by, reason);
+
+        for amendment_id in self.proposal_amendments(root_id).iter() {
+            if !self.proposal_active(amendment_id).get() {
+                continue;
+            }
+            self.proposal_deadline(amendment_id).set(new_deadline);
+            self.deadline_extended_event(amendment_id, new_deadline, extended_by, reason);
+        }
+    }
+
+    fn load_voting_power(&self, proposal_id: u64) -> VotingPowerMode {
+        let mapper = self.proposal_voting_power(proposal_id);
+        if mapper.is_empty() {
+            VotingPowerMode::OnePerAddress
+        } else {
+            mapper.get()
+        }
+    }
+
+    fn load_treasury_spend(&self, proposal_id: u64) -> Option<TreasurySpend<Self::Api>> {
+        let mapper = self.treasury_spend(proposal_id);
+        if mapper.is_empty() {
+            None
+        } else {
+            Some(mapper.get())
+        }
+    }
+
+    fn has_governance_action(&self, proposal_id: u64) -> bool {
+        !self.treasury_spend(proposal_id).is_empty()
+            || !self.stream_cancellation(proposal_id).is_empty()
+            || !self.code_upgrade(proposal_id).is_empty()
+    }
+
+    // Só eleva quórum e limiar; a categoria não consegue reduzi-los depois da publicação
+    fn apply_governance_action_rules(&self, proposal_id: u64) {
+        require!(
+            !self.governance_action_quorum().is_empty(),
+            "Governance action rules not set"
+        );
+
+        let quorum = self.governance_action_quorum().get();
+        let threshold_bps = self.governance_action_threshold_bps().get();
+        self.proposal_quorum(proposal_id).update(|current| *current = core::cmp::max(*current, quorum));
+        self.proposal_threshold_bps(proposal_id)
+            .update(|current| *current = core::cmp::max(*current, threshold_bps));
+    }
+
+    fn code_upgrade_eta(&self, proposal_id: u64) -> u64 {
+        self.proposal_deadline(proposal_id).get() + CODE_UPGRADE_DELAY
+    }
+
+    fn is_governance_action_approved(&self, proposal_id: u64) -> bool {
+        match self.load_proposal_outcome(proposal_id) {
+            Some(outcome) => outcome.passed && outcome.winning_option == 0,
+            None => false,
+        }
+    }
+
+    fn is_treasury_spend_approved(&self, proposal_id: u64) -> bool {
+        !self.treasury_spend(proposal_id).is_empty()
+            && !self.treasury_spend_executed(proposal_id).get()
+            && self.is_governance_action_approved(proposal_id)
+    }
+
+    fn try_execute_treasury_spend(&self, proposal_id: u64) -> bool {
+        let spend = self.treasury_spend(proposal_id).get();
+        if self.treasury_balance(&spend.token_id, spend.token_nonce).get() < spend.amount {
+            return false;
+        }
+
+        self.treasury_spend_executed(proposal_id).set(true);
+        if self.spend_vesting(proposal_id).is_empty() {
+            self.debit_treasury(
+                proposal_id,
+                &spend.recipient,
+                &spend.token_id,
+                spend.token_nonce,
+                &spend.amount,
+            );
+        } else {
+            self.create_vesting_stream(proposal_id, spend);
+        }
+        true
+    }
+
+    // Os fundos saem da tesouraria na criação e ficam reservados para o stream
+    fn create_vesting_stream(&self, proposal_id: u64, spend: TreasurySpend<Self::Api>) {
+        self.remove_treasury_balance(&spend.token_id, spend.token_nonce, &spend.amount);
+        self.treasury_withdrawal_event(
+            proposal_id,
+            &spend.recipient,
+            &spend.token_id,
+            spend.token_nonce,
+            &spend.amount,
+        );
+
+        let stream = VestingStream {
+            proposal_id,
+            recipient: spend.recipient,
+            token_id: spend.token_id,
+            token_nonce: spend.token_nonce,
+            amount: spend.amount,
+            schedule: self.spend_vesting(proposal_id).get(),
+            claimed: BigUint::zero(),
+            cancelled_at: None,
+        };
+        let mut streams = self.vesting_streams();
+        streams.push(&stream);
+        let stream_id = streams.len();
+        self.streams_by_recipient(&stream.recipient).push(&stream_id);
+
+        self.stream_created_event(stream_id, proposal_id, &stream.recipient, &stream.amount);
+    }
+
+    fn require_open_stream(&self, stream_id: usize) {
+        require!(
+            stream_id > 0 && stream_id <= self.vesting_streams().len(),
+            "Stream does not exist"
+        );
+        let stream = self.vesting_streams().get(stream_id);
+        require!(stream.cancelled_at.is_none(), "Stream already cancelled");
+    }
+
+    // Liberação linear entre start e end, nada antes do cliff; congela no cancelamento
+    fn stream_vested(&self, stream: &VestingStream<Self::Api>) -> BigUint {
+        let mut time = self.blockchain().get_block_timestamp();
+        if let Some(cancelled_at) = stream.cancelled_at {
+            time = core::cmp::min(time, cancelled_at);
+        }
+
+        let schedule = &stream.schedule;
+        if time < schedule.cliff {
+            return BigUint::zero();
+        }
+        if time >= schedule.end {
+            return stream.amount.clone();
+        }
+        stream.amount.clone() * (time - schedule.start) / (schedule.end - schedule.start)
+    }
+
+    fn stream_claimable(&self, stream: &VestingStream<Self::Api>) -> BigUint {
+        self.stream_vested(stream) - &stream.claimed
+    }
+
+    fn credit_treasury(
+        &self,
+        depositor: &ManagedAddress,
+        token_id: &EgldOrEsdtTokenIdentifier,
+        token_nonce: u64,
+        amount: &BigUint,
+    ) {
+        self.treasury_balance(token_id, token_nonce)
+            .update(|balance| *balance += amount);
+        self.treasury_assets().insert((token_id.clone(), token_nonce));
+        self.treasury_deposit_event(depositor, token_id, token_nonce, amount);
+    }
+
+    // Toda saída da tesouraria passa por aqui
+    fn debit_treasury(
+        &self,
+        proposal_id: u64,
+        recipient: &ManagedAddress,
+        token_id: &EgldOrEsdtTokenIdentifier,
+        token_nonce: u64,
+        amount: &BigUint,
+    ) {
+        self.remove_treasury_balance(token_id, token_nonce, amount);
+
+        self.tx()
+            .to(recipient)
+            .egld_or_single_esdt(token_id, token_nonce, amount)
+            .transfer();
+        self.treasury_withdrawal_event(proposal_id, recipient, token_id, token_nonce, amount);
+    }
+
+    fn remove_treasury_balance(
+        &self,
+        token_id: &EgldOrEsdtTokenIdentifier,
+        token_nonce: u64,
+        amount: &BigUint,
+    ) {
+        let balance_mapper = self.treasury_balance(token_id, token_nonce);
+        let balance = balance_mapper.get();
+        require!(balance >= *amount, "Insufficient treasury balance");
+
+        if balance == *amount {
+            balance_mapper.clear();
+            self.treasury_assets().swap_remove(&(token_id.clone(), token_nonce));
+        } else {
+            balance_mapper.set(&(balance - amount));
+        }
+    }
+
+    fn load_nft_gate(&self, proposal_id: u64) -> Option<NftGate<Self::Api>> {
+        let mapper = self.nft_gate(proposal_id);
+        if mapper.is_empty() {
+            None
+        } else {
+            Some(mapper.get())
+        }
+    }
+
+    fn load_voter_weight(&self, proposal_id: u64, voter: &ManagedAddress) -> u64 {
+        if !self.has_voted(proposal_id, voter).get() {
+            return 0;
+        }
+        match self.load_voting_power(proposal_id) {
+            VotingPowerMode::OnePerAddress => 1,
+            VotingPowerMode::VoteEscrow | VotingPowerMode::Nft => {
+                self.voter_weight(proposal_id, voter).get()
+            },
+        }
+    }
+
+    fn attach_creator_bounty(&self, proposal_id: u64) {
+        let payment = self.call_value().egld_or_single_esdt();
+        if payment.amount == 0u32 {
+            return;
+        }
+
+        let creator = self.blockchain().get_caller();
+        self.creator_bounty(proposal_id).set(&payment);
+        self.creator_bounty_rule(proposal_id).set(self.load_default_bounty_rule());
+        self.creator_bounty_attached_event(proposal_id, &creator, &payment.amount);
+    }
+
+    fn load_default_bounty_rule(&self) -> BountyRule {
+        let mapper = self.default_bounty_rule();
+        if mapper.is_empty() {
+            BountyRule::AllVoters
+        } else {
+            mapper.get()
+        }
+    }
+
+    fn load_creator_bounty_rule(&self, proposal_id: u64) -> BountyRule {
+        let mapper = self.creator_bounty_rule(proposal_id);
+        if mapper.is_empty() {
+            BountyRule::AllVoters
+        } else {
+            mapper.get()
+        }
+    }
+
+    // Peso total dos eleitores que podem receber a recompensa
+    fn creator_bounty_eligible_weight(&self, proposal_id: u64) -> u64 {
+        match self.load_creator_bounty_rule(proposal_id) {
+            BountyRule::AllVoters => self.load_total_weight(proposal_id),
+            BountyRule::WinningVoters => match self.load_proposal_outcome(proposal_id) {
+                Some(outcome) if outcome.winning_votes > 0 => {
+                    self.proposal_option_votes(proposal_id, outcome.winning_option).get()
+                },
+                _ => 0,
+            },
+            BountyRule::ReturnToCreator => 0,
+        }
+    }
+
+    fn compute_creator_bounty_share(&self, proposal_id: u64, voter: &ManagedAddress) -> BigUint {
+        let total_weight = self.creator_bounty_eligible_weight(proposal_id);
+        if total_weight == 0 {
+            return BigUint::zero();
+        }
+
+        let weight = self.load_voter_weight(proposal_id, voter);
+        if self.load_creator_bounty_rule(proposal_id) == BountyRule::WinningVoters {
+            let winning_option = self.proposal_outcome(proposal_id).get().winning_option;
+            if weight == 0 || self.voter_choice(proposal_id, voter).get() != winning_option {
+                return BigUint::zero();
+            }
+        }
+        self.creator_bounty(proposal_id).get().amount * weight / total_weight
+    }
+
+    // A taxa sai do lado perdedor na finalização, arredondada para baixo.
+    // Empate, resultado reprovado ou nenhum apostador no vencedor: todas as
+    // apostas são devolvidas
+    fn settle_prediction_market(&self, proposal_id: u64, outcome: &ProposalOutcome) {
+        let winning_staked = self
+            .prediction_option_staked(proposal_id, outcome.winning_option)
+            .get();
+        if !outcome.passed
+            || outcome.winning_votes == 0
+            || self.is_leading_option_tied(proposal_id, outcome.winning_votes)
+            || winning_staked == 0u32
+        {
+            return;
+        }
+        self.prediction_winning_option(proposal_id)
+            .set(Some(outcome.winning_option));
+
+        let market = self.prediction_market(proposal_id).get();
+        let losing_pool = self.prediction_total_staked(proposal_id).get() - winning_staked;
+        let fee = losing_pool * market.fee_bps / MAX_BPS;
+        if fee > 0u32 {
+            let own_address = self.blockchain().get_sc_address();
+            self.credit_treasury(&own_address, &market.token_id, 0, &fee);
+        }
+        self.prediction_fee_collected(proposal_id).set(&fee);
+    }
+
+    fn is_leading_option_tied(&self, proposal_id: u64, leading_votes: u64) -> bool {
+        let options_count = core::cmp::max(self.proposal_options(proposal_id).len(), 1);
+        let leaders = (0..options_count)
+            .filter(|&option| self.proposal_option_votes(proposal_id, option).get() == leading_votes)
+            .count();
+        leaders > 1
+    }
+
+    fn is_prediction_winner(
+        &self,
+        proposal_id: u64,
+        position: &PredictionPosition<Self::Api>,
+    ) -> bool {
+        self.prediction_winning_option(proposal_id).get() == Some(position.option)
+    }
+
+    // Lado perdedor menos a taxa, a ser dividido entre os vencedores
+    fn prediction_distributable(&self, proposal_id: u64, winning_option: usize) -> BigUint {
+        let winning_staked = self.prediction_option_staked(proposal_id, winning_option).get();
+        self.prediction_total_staked(proposal_id).get()
+            - winning_staked
+            - self.prediction_fee_collected(proposal_id).get()
+    }
+
+    fn compute_prediction_payout(&self, proposal_id: u64, staker: &ManagedAddress) -> BigUint {
+        let position_mapper = self.prediction_position(proposal_id, staker);
+        if position_mapper.is_empty() {
+            return BigUint::zero();
+        }
+        let position = position_mapper.get();
+
+        match self.prediction_winning_option(proposal_id).get() {
+            None => position.amount,
+            Some(winning_option) if winning_option == position.option => {
+                let winning_staked = self
+                    .prediction_option_staked(proposal_id, winning_option)
+                    .get();
+                let winnings = self.prediction_distributable(proposal_id, winning_option)
+                    * &position.amount
+                    / winning_staked;
+                position.amount + winnings
+            },
+            Some(_) => BigUint::zero(),
+        }
+    }
+
+    // Quando o último vencedor resgata, a poeira do arredondamento vai para a tesouraria
+    fn track_prediction_winnings(
+        &self,
+        proposal_id: u64,
+        position: &PredictionPosition<Self::Api>,
+        payout: &BigUint,
+    ) {
+        let winnings = payout - &position.amount;
+        self.prediction_paid_winnings(proposal_id)
+            .update(|paid| *paid += &winnings);
+        self.prediction_claimed_stake(proposal_id)
+            .update(|claimed| *claimed += &position.amount);
+
+        let winning_staked = self.prediction_option_staked(proposal_id, position.option).get();
+        if self.prediction_claimed_stake(proposal_id).get() < winning_staked {
+            return;
+        }
+
+        let dust = self.prediction_distributable(proposal_id, position.option)
+            - self.prediction_paid_winnings(proposal_id).get();
+        if dust > 0u32 {
+            let market = self.prediction_market(proposal_id).get();
+            let own_address = self.blockchain().get_sc_address();
+            self.credit_treasury(&own_address, &market.token_id, 0, &dust);
+        }
+    }
+
+    fn voting_reward_deadline(&self, proposal_id: u64) -> u64 {
+        self.proposal_deadline(proposal_id)
+            .get()
+            .saturating_add(VOTING_REWARD_CLAIM_PERIOD)
+    }
+
+    fn is_voting_reward_refundable(&self, proposal_id: u64) -> bool {
+        if self.compute_proposal_status(proposal_id) == ProposalStatus::Cancelled {
+            return true;
+        }
+        !self.proposal_outcome(proposal_id).is_empty() && self.load_total_weight(proposal_id) == 0
+    }
+
+    // Divisão proporcional ao peso do voto, arredondada para baixo
+    fn compute_voting_reward(&self, proposal_id: u64, voter: &ManagedAddress) -> BigUint {
+        let weight = self.load_voter_weight(proposal_id, voter);
+        let total_weight = self.load_total_weight(proposal_id);
+        if weight == 0 || total_weight == 0 {
+            return BigUint::zero();
+        }
+        self.voting_reward_pool(proposal_id).get().amount * weight / total_weight
+    }
+
+    fn load_total_weight(&self, proposal_id: u64) -> u64 {
+        match self.load_voting_power(proposal_id) {
+            VotingPowerMode::OnePerAddress => self.proposal_vote_count(proposal_id).get(),
+            VotingPowerMode::VoteEscrow | VotingPowerMode::Nft => {
+                self.proposal_total_weight(proposal_id).get()
+            },
+        }
+    }
+
+    // Emendas usam o snapshot da proposta original
+    fn snapshot_time(&self, proposal_id: u64) -> u64 {
+        let root_id = self.proposal_parent(proposal_id).get().unwrap_or(proposal_id);
+        self.proposal_start(root_id).get()
+    }
+
+    fn compute_vote_weight(&self, proposal_id: u64, voter: &ManagedAddress) -> u64 {
+        let mode = self.load_voting_power(proposal_id);
+        if mode != VotingPowerMode::Nft {
+            require!(
+                *self.call_value().egld_value() == 0u32
+                    && self.call_value().all_esdt_transfers().is_empty(),
+                "Payment not accepted"
+            );
+        }
+
+        match mode {
+            VotingPowerMode::OnePerAddress => 1,
+            VotingPowerMode::Nft => self.take_nft_votes(proposal_id, voter),
+            VotingPowerMode::VoteEscrow => {
+                let power = self.voting_power_at(voter, self.snapshot_time(proposal_id));
+                let power_unit = self.vote_escrow_config().get().power_unit;
+                let weight = (power / power_unit).to_u64().unwrap_or(u64::MAX);
+                require!(weight > 0, "No voting power");
+                weight
+            },
+        }
+    }
+
+    // Peso = quantidade de NFTs/SFTs enviados
+    fn take_nft_votes(&self, proposal_id: u64, voter: &ManagedAddress) -> u64 {
+        let gate = self.nft_gate(proposal_id).get();
+        let payments = self.call_value().all_esdt_transfers().clone();
+        require!(!payments.is_empty(), "NFTs required to vote");
+
+        let mut weight = 0u64;
+        for payment in payments.iter() {
+            require!(
+                payment.token_identifier == gate.collection && payment.token_nonce > 0,
+                "Invalid NFT collection"
+            );
+            let used_mapper = self.nft_nonce_used(proposal_id, payment.token_nonce);
+            require!(!used_mapper.get(), "NFT already voted on this proposal");
+            used_mapper.set(true);
+            weight = match payment.amount.to_u64().and_then(|amount| weight.checked_add(amount)) {
+                Some(total) => total,
+                None => sc_panic!("Voting weight overflow"),
+            };
+        }
+
+        if gate.return_immediately {
+            self.tx().to(voter).payment(payments).transfer();
+        } else {
+            for payment in payments.iter() {
+                self.nft_deposits(proposal_id, voter).push(&*payment);
+            }
+        }
+        weight
+    }
+
+    fn require_valid_unlock_time(&self, unlock_time: u64) {
+        let current_time = self.blockchain().get_block_timestamp();
+        let max_lock_duration = self.vote_escrow_config().get().max_lock_duration;
+        require!(
+            unlock_time > current_time && unlock_time - current_time <= max_lock_duration,
+            "Invalid unlock time"
+        );
+    }
+
+    fn load_vote_escrow_lock(&self, user: &ManagedAddress) -> VoteEscrowLock<Self::Api> {
+        let checkpoints = self.vote_escrow_checkpoints(user);
+        if checkpoints.is_empty() {
+            VoteEscrowLock {
+                amount: BigUint::zero(),
+                unlock_time: 0,
+                locked_at: 0,
+            }
+        } else {
+            checkpoints.get(checkpoints.len())
+        }
+    }
+
+    // Alterações no mesmo bloco sobrescrevem o último checkpoint
+    fn push_vote_escrow_checkpoint(&self, user: &ManagedAddress, amount: BigUint, unlock_time: u64) {
+        let current_time = self.blockchain().get_block_timestamp();
+        let lock = VoteEscrowLock {
+            amount,
+            unlock_time,
+            locked_at: current_time,
+        };
+
+        let mut checkpoints = self.vote_escrow_checkpoints(user);
+        let len = checkpoints.len();
+        if len > 0 && checkpoints.get(len).locked_at == current_time {
+            checkpoints.set(len, &lock);
+        } else {
+            checkpoints.push(&lock);
+        }
+        self.vote_escrow_lock_updated_event(user, lock.unlock_time, &lock.amount);
+    }
+
+    // poder = quantidade * tempo restante / duração máxima, pelo checkpoint vigente em `timestamp`
+    fn voting_power_at(&self, user: &ManagedAddress, timestamp: u64) -> BigUint {
+        let checkpoints = self.vote_escrow_checkpoints(user);
+
+        let mut low = 1usize;
+        let mut high = checkpoints.len();
+        let mut found = 0usize;
+        while low <= high {
+            let middle = (low + high) / 2;
+            if checkpoints.get(middle).locked_at <= timestamp {
+                found = middle;
+                low = middle + 1;
+            } else {
+                high = middle - 1;
+            }
+        }
+        if found == 0 {
+            return BigUint::zero();
+        }
+
+        let lock = checkpoints.get(found);
+        if timestamp >= lock.unlock_time {
+            return BigUint::zero();
+        }
+        let max_lock_duration = self.vote_escrow_config().get().max_lock_duration;
+        lock.amount * (lock.unlock_time - timestamp) / max_lock_duration
+    }
+
+    fn require_conviction_proposal(&self, proposal_id: u64) {
+        require!(proposal_id < self.total_proposals().get(), "Proposal does not exist");
+        self.require_default_space(proposal_id);
+        require!(
+            !self.conviction_request(proposal_id).is_empty(),
+            "Not a conviction proposal"
+        );
+    }
+
+    fn require_active_conviction_proposal(&self, proposal_id: u64) {
+        self.require_conviction_proposal(proposal_id);
+        require!(
+            self.compute_proposal_status(proposal_id) == ProposalStatus::Active,
+            "Proposal is not active"
+        );
+    }
+
+    fn load_conviction_request(&self, proposal_id: u64) -> Option<ConvictionRequest<Self::Api>> {
+        let mapper = self.conviction_request(proposal_id);
+        if mapper.is_empty() {
+            None
+        } else {
+            Some(mapper.get())
+        }
+    }
+
+    // O número de apoiadores ocupa o lugar da contagem de votos
+    fn add_conviction_support(&self, proposal_id: u64, staker: &ManagedAddress, amount: &BigUint) {
+        self.update_conviction(proposal_id);
+
+        let stake_mapper = self.conviction_stake(proposal_id, staker);
+        if stake_mapper.is_empty() {
+            self.proposal_vote_count(proposal_id).update(|x| *x += 1);
+        }
+        stake_mapper.update(|stake| *stake += amount);
+        self.conviction_staked(proposal_id).update(|staked| *staked += amount);
+
+        self.conviction_support_changed_event(
+            proposal_id,
+            staker,
+            &self.conviction_staked(proposal_id).get(),
+        );
+    }
+
+    fn remove_conviction_support(&self, proposal_id: u64, staker: &ManagedAddress, amount: &BigUint) {
+        self.require_conviction_proposal(proposal_id);
+        require!(*amount > 0u32, "Amount must be greater than zero");
+
+        let stake_mapper = self.conviction_stake(proposal_id, staker);
+        let stake = stake_mapper.get();
+        require!(stake >= *amount, "Insufficient stake");
+
+        if self.compute_proposal_status(proposal_id) == ProposalStatus::Active {
+            self.update_conviction(proposal_id);
+        }
+        if stake == *amount {
+            stake_mapper.clear();
+            self.proposal_vote_count(proposal_id).update(|x| *x -= 1);
+        } else {
+            stake_mapper.set(&(stake - amount));
+        }
+        self.conviction_staked(proposal_id).update(|staked| *staked -= amount);
+
+        self.conviction_support_changed_event(
+            proposal_id,
+            staker,
+            &self.conviction_staked(proposal_id).get(),
+        );
+    }
+
+    // y(t) = a^t * y(0) + x * (1 - a^t) / (1 - a), com t em períodos completos
+    fn compute_conviction(&self, proposal_id: u64) -> (BigUint, u64) {
+        let config = self.conviction_config().get();
+        let last_update = self.conviction_last_update(proposal_id).get();
+        let conviction = self.conviction_value(proposal_id).get();
+
+        let current_time = self.blockchain().get_block_timestamp();
+        let periods = (current_time - last_update) / config.period;
+        if periods == 0 {
+            return (conviction, last_update);
+        }
+
+        let decay = config.decay_bps * (CONVICTION_SCALE / MAX_BPS);
+        let decay_t = decay_power(decay, periods);
+        let staked = self.conviction_staked(proposal_id).get();
+
+        let conviction = conviction * decay_t / CONVICTION_SCALE
+            + staked * (CONVICTION_SCALE - decay_t) / (CONVICTION_SCALE - decay);
+        (conviction, last_update + periods * config.period)
+    }
+
+    fn update_conviction(&self, proposal_id: u64) -> BigUint {
+        let (conviction, last_update) = self.compute_conviction(proposal_id);
+        self.conviction_value(proposal_id).set(&conviction);
+        self.conviction_last_update(proposal_id).set(last_update);
+        conviction
+    }
+
+    // limiar = ρ * total em stake / ((1 - a) * (β - pedido / pool)²)
+    // O pool de convicção faz o papel do tesouro; com o pool vazio nada é aprovado
+    fn conviction_threshold(&self, proposal_id: u64) -> Option<BigUint> {
+        let config = self.conviction_config().get();
+        let pool = self.conviction_pool().get();
+        if pool == 0u32 {
+            return None;
+        }
+
+        let requested = self.conviction_request(proposal_id).get().amount;
+        let ratio_bps = (requested * MAX_BPS / &pool).to_u64().unwrap_or(u64::MAX);
+        if ratio_bps >= config.max_ratio_bps {
+            return None;
+        }
+
+        let distance = config.max_ratio_bps - ratio_bps;
+        let decay = config.decay_bps * (CONVICTION_SCALE / MAX_BPS);
+        Some(
+            self.conviction_total_staked().get() * config.weight_bps * MAX_BPS * CONVICTION_SCALE
+                / (BigUint::from(CONVICTION_SCALE - decay) * distance * distance),
+        )
+    }
+
+    // Com a execução pausada o limiar atingido fica registrado e o pagamento
+    // espera por execute_conviction_proposal
+    fn try_pass_conviction_proposal(&self, proposal_id: u64) -> bool {
+        let conviction = self.update_conviction(proposal_id);
+        let reached_mapper = self.conviction_threshold_reached(proposal_id);
+        if !reached_mapper.get() {
+            let threshold = match self.conviction_threshold(proposal_id) {
+                Some(threshold) => threshold,
+                None => return false,
+            };
+            if conviction < threshold || conviction == 0u32 {
+                return false;
+            }
+        }
+
+        if self.is_operation_paused(PauseScope::Execution) {
+            if !reached_mapper.get() {
+                reached_mapper.set(true);
+                self.conviction_threshold_reached_event(proposal_id, &conviction);
+            }
+            return false;
+        }
+
+        let request = self.conviction_request(proposal_id).get();
+        if self.conviction_pool().get() < request.amount {
+            return false;
+        }
+        let supporters = self.proposal_vote_count(proposal_id).get();
+        self.proposal_outcome(proposal_id).set(ProposalOutcome {
+            winning_option: 0,
+            winning_votes: supporters,
+            total_votes: supporters,
+            quorum_reached: true,
+            threshold_reached: true,
+            passed: true,
+            adopted_amendment: None,
+        });
+        self.proposal_active(proposal_id).set(false);
+        self.active_proposals().swap_remove(&proposal_id);
+
+        self.conviction_pool().update(|pool| *pool -= &request.amount);
+        self.tx().to(&request.beneficiary).egld(&request.amount).transfer();
+        self.conviction_proposal_passed_event(
+            proposal_id,
+            &request.beneficiary,
+            &request.amount,
+        );
+        true
+    }
+
+    fn require_draft_owned_by_caller(&self, proposal_id: u64) {
+        let caller = self.blockchain().get_caller();
+
+        require!(proposal_id < self.total_proposals().get(), "Proposal does not exist");
+        self.require_default_space(proposal_id);
+        require!(
+            caller == self.load_proposal_header(proposal_id).creator,
+            "Only creator can edit proposal"
+        );
+        require!(
+            self.compute_proposal_status(proposal_id) == ProposalStatus::Draft,
+            "Proposal is not a draft"
+        );
+    }
+
+    fn require_space_exists(&self, space_id: usize) {
+        require!(
+            space_id != 0 && space_id <= self.space_names().len(),
+            "Space does not exist"
+        );
+    }
+
+    fn require_space_admin(&self, space_id: usize) {
+        self.require_space_exists(space_id);
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.space_admins(space_id).contains(&caller),
+            "Only space admin can call this function"
+        );
+    }
+
+    // Propostas de espaços só são alteradas pelos endpoints do espaço
+    fn require_default_space(&self, proposal_id: u64) {
+        require!(
+            self.proposal_space(proposal_id).is_empty(),
+            "Proposal belongs to a space"
+        );
+    }
+
+    fn resolve_space_proposal(&self, space_id: usize, local_id: u64) -> u64 {
+        self.require_space_exists(space_id);
+        let proposals = self.space_proposals(space_id);
+        require!(local_id < proposals.len() as u64, "Proposal does not exist");
+        proposals.get(local_id as usize + 1)
+    }
+
+    fn require_category_exists(&self, category_id: usize) {
+        require!(
+            category_id != UNCATEGORIZED && category_id <= self.categories().len(),
+            "Category does not exist"
+        );
+    }
+
+    fn require_valid_category_rules(&self, threshold_bps: u64, min_duration: u64) {
+        require!(threshold_bps <= MAX_BPS, "Invalid threshold");
+        require!(min_duration >= MIN_DURATION, "Duration too short");
+    }
+
+    fn compute_proposal_status(&self, proposal_id: u64) -> ProposalStatus {
+        let outcome_mapper = self.proposal_outcome(proposal_id);
+        if !outcome_mapper.is_empty() {
+            return if outcome_mapper.get().passed {
+                ProposalStatus::Passed
+            } else {
+                ProposalStatus::Rejected
+            };
+        }
+        if !self.proposal_active(proposal_id).get() {
+            return ProposalStatus::Cancelled;
+        }
+        if self.proposal_draft(proposal_id).get() {
+            return ProposalStatus::Draft;
+        }
+
+        let current_time = self.blockchain().get_block_timestamp();
+        if current_time > self.proposal_deadline(proposal_id).get() {
+            ProposalStatus::Ended
+        } else {
+            ProposalStatus::Active
+        }
+    }
+
+    fn build_proposal(&self, proposal_id: u64) -> Proposal<Self::Api> {
+        let header = self.load_proposal_header(proposal_id);
+        Proposal {
+            id: proposal_id,
+            title: header.title,
+            description: header.description,
+            creator: header.creator,
+            deadline: self.proposal_deadline(proposal_id).get(),
+            vote_count: self.proposal_vote_count(proposal_id).get(),
+            status: self.compute_proposal_status(proposal_id),
+            options: self.proposal_options(proposal_id).iter().collect(),
+            option_votes: self.build_option_votes(proposal_id),
+            document: self.load_proposal_document(proposal_id),
+            category_id: self.proposal_category(proposal_id).get(),
+            tags: self.proposal_tags(proposal_id).get(),
+            edit_count: self.proposal_edit_count(proposal_id).get(),
+            parent_id: self.proposal_parent(proposal_id).get(),
+            outcome: self.load_proposal_outcome(proposal_id),
+            ballot_type: self.load_ballot_type(proposal_id),
+            score_range: self.load_score_range(proposal_id),
+            seats: self.load_seats(proposal_id),
+            conviction_request: self.load_conviction_request(proposal_id),
+            voting_power: self.load_voting_power(proposal_id),
+            nft_gate: self.load_nft_gate(proposal_id),
+            treasury_spend: self.load_treasury_spend(proposal_id),
+        }
+    }
+
+    fn load_proposal_outcome(&self, proposal_id: u64) -> Option<ProposalOutcome> {
+        let mapper = self.proposal_outcome(proposal_id);
+        if mapper.is_empty() {
+            None
+        } else {
+            Some(mapper.get())
+        }
+    }
+
+    // Sem saldo suficiente no pool a finalização segue sem recompensa
+    // Sem recompensa para propostas sem votos ou finalizadas pelo próprio criador,
+    // para que criar e finalizar propostas vazias não drene o pool
+    fn pay_finalization_bounty(&self, proposal_id: u64, caller: &ManagedAddress) {
+        if self.proposal_vote_count(proposal_id).get() == 0
+            || caller == &self.load_proposal_header(proposal_id).creator
+        {
+            return;
+        }
+
+        let bounty = self.finalization_bounty().get();
+        if bounty == 0u32 || self.finalization_pool().get() < bounty {
+            return;
+        }
+
+        self.finalization_pool().update(|pool| *pool -= &bounty);
+        self.tx().to(caller).egld(&bounty).transfer();
+        self.finalization_bounty_paid_event(caller, &bounty);
+    }
+
+    fn load_proposal_document(&self, proposal_id: u64) -> Option<ProposalDocument<Self::Api>> {
+        let mapper = self.proposal_document(proposal_id);
+        if mapper.is_empty() {
+            None
+        } else {
+            Some(mapper.get())
+        }
+    }
+
+    fn build_option_votes(&self, proposal_id: u64) -> ManagedVec<u64> {
+        let options_count = core::cmp::max(self.proposal_options(proposal_id).len(), 1);
+
+        let mut option_votes = ManagedVec::new();
+        for option in 0..options_count {
+            option_votes.push(self.proposal_option_votes(proposal_id, option).get());
+        }
+        option_votes
+    }
+
+    fn page_bounds(&self, from: usize, count: usize, len: usize) -> (usize, usize) {
+        let start = core::cmp::min(from, len);
+        let end = core::cmp::min(start + core::cmp::min(count, MAX_PAGE_SIZE), len);
+        (start, end)
+    }
+
+    fn collect_proposals_with_status<I: Iterator<Item = u64>>(
+        &self,
+        proposal_ids: I,
+        status: ProposalStatus,
+        from: usize,
+        count: usize,
+    ) -> MultiValueEncoded<Proposal<Self::Api>> {
+        let page_size = core::cmp::min(count, MAX_PAGE_SIZE);
+        let mut result = MultiValueEncoded::new();
+        if page_size == 0 {
+            return result;
+        }
+
+        let mut skipped = 0usize;
+        let mut collected = 0usize;
+        for proposal_id in proposal_ids {
+            if !self.proposal_space(proposal_id).is_empty()
+                || self.compute_proposal_status(proposal_id) != status
+            {
+                continue;
+            }
+            if skipped < from {
+                skipped += 1;
+                continue;
+            }
+
+            result.push(self.build_proposal(proposal_id));
+            collected += 1;
+            if collected == page_size {
+                break;
+            }
+        }
+        result
+    }
+
+    // ============= EVENTOS =============
+
+    #[event("contractInitialized")]
+    fn contract_initialized_event(&self, #[indexed] owner: &ManagedAddress);
+
+    #[event("proposalCreated")]
+    fn proposal_created_event(
+        &self,
+        #[indexed] proposal_id: u64,
+        #[indexed] creator: &ManagedAddress,
+    );
+
+    #[event("proposalEdited")]
+    fn proposal_edited_event(
+        &self,
+        #[indexed] proposal_id: u64,
+        #[indexed] editor: &ManagedAddress,
+        #[indexed] edit_count: u64,
+    );
+
+    #[event("proposalPublished")]
+    fn proposal_published_event(&self, #[indexed] proposal_id: u64, #[indexed] deadline: u64);
+
+    #[event("amendmentCreated")]
+    fn amendment_created_event(
+        &self,
+        #[indexed] parent_id: u64,
+        #[indexed] amendment_id: u64,
+        #[indexed] creator: &ManagedAddress,
+    );
+
+    #[event("voteCast")]
+    fn vote_cast_event(
+        &self,
+        #[indexed] proposal_id: u64,
+        #[indexed] voter: &ManagedAddress,
+    );
+
+    #[event("ballotTypeSet")]
+    fn ballot_type_set_event(&self, #[indexed] proposal_id: u64, #[indexed] ballot_type: BallotType);
+
+    #[event("proposalSeatsSet")]
+    fn proposal_seats_set_event(&self, #[indexed] proposal_id: u64, #[indexed] seats: usize);
+
+    #[event("votingPowerSet")]
+    fn voting_power_set_event(&self, #[indexed] proposal_id: u64, #[indexed] mode: VotingPowerMode);
+
+    #[event("proposalDocumentSet")]
+    fn proposal_document_set_event(
+        &self,
+        #[indexed] proposal_id: u64,
+        #[indexed] hash_algorithm: HashAlgorithm,
+        content_hash: &ManagedBuffer,
+    );
+
+    #[event("proposalCategorySet")]
+    fn proposal_category_set_event(
+        &self,
+        #[indexed] proposal_id: u64,
+        #[indexed] category_id: usize,
+    );
+
+    #[event("proposalTagsSet")]
+    fn proposal_tags_set_event(
+        &self,
+        #[indexed] proposal_id: u64,
+        tags: &ManagedVec<ManagedBuffer>,
+    );
+
+    #[event("deadlineExtended")]
+    fn deadline_extended_event(
+        &self,
+        #[indexed] proposal_id: u64,
+        #[indexed] new_deadline: u64,
+        #[indexed] extended_by: &ManagedAddress,
+        reason: &ManagedBuffer,
+    );
+
+    #[event("proposalCancelled")]
+    fn proposal_cancelled_event(
+        &self, 
+        #[indexed] proposal_id: u64, 
+        #[indexed] cancelled_by: &ManagedAddress
+    );
+
+    #[event("proposalFinalized")]
+    fn proposal_finalized_event(
+        &self,
+        #[indexed] proposal_id: u64,
+        #[indexed] passed: bool,
+        #[indexed] winning_option: usize,
+        #[indexed] finalized_by: &ManagedAddress,
+        option_votes: &ManagedVec<u64>,
+    );
+
+    #[event("rankedCountProgress")]
+    fn ranked_count_progress_event(
+        &self,
+        #[indexed] proposal_id: u64,
+        #[indexed] round: usize,
+        #[indexed] next_ballot: usize,
+    );
+
+    #[event("rankedCountCompleted")]
+    fn ranked_count_completed_event(
+        &self,
+        #[indexed] proposal_id: u64,
+        #[indexed] winning_option: usize,
+        #[indexed] final_round: usize,
+    );
+
+    #[event("convictionPoolFunded")]
+    fn conviction_pool_funded_event(&self, #[indexed] funder: &ManagedAddress, amount: &BigUint);
+
+    #[event("convictionSupportChanged")]
+    fn conviction_support_changed_event(
+        &self,
+        #[indexed] proposal_id: u64,
+        #[indexed] staker: &ManagedAddress,
+        total_staked: &BigUint,
+    );
+
+    #[event("convictionThresholdReached")]
+    fn conviction_threshold_reached_event(&self, #[indexed] proposal_id: u64, conviction: &BigUint);
+
+    #[event("convictionProposalPassed")]
+    fn conviction_proposal_passed_event(
+        &self,
+        #[indexed] proposal_id: u64,
+        #[indexed] beneficiary: &ManagedAddress,
+        amount: &BigUint,
+    );
+
+    #[event("voteEscrowLockUpdated")]
+    fn vote_escrow_lock_updated_event(
+        &self,
+        #[indexed] user: &ManagedAddress,
+        #[indexed] unlock_time: u64,
+        amount: &BigUint,
+    );
+
+    #[event("votingPowerUsed")]
+    fn voting_power_used_event(
+        &self,
+        #[indexed] proposal_id: u64,
+        #[indexed] voter: &ManagedAddress,
+        #[indexed] weight: u64,
+    );
+
+    #[event("treasuryDeposit")]
+    fn treasury_deposit_event(
+        &self,
+        #[indexed] depositor: &ManagedAddress,
+        #[indexed] token_id: &EgldOrEsdtTokenIdentifier,
+        #[indexed] token_nonce: u64,
+        amount: &BigUint,
+    );
+
+    #[event("treasuryWithdrawal")]
+    fn treasury_withdrawal_event(
+        &self,
+        #[indexed] proposal_id: u64,
+        #[indexed] recipient: &ManagedAddress,
+        #[indexed] token_id: &EgldOrEsdtTokenIdentifier,
+        #[indexed] token_nonce: u64,
+        amount: &BigUint,
+    );
+
+    #[event("streamCreated")]
+    fn stream_created_event(
+        &self,
+        #[indexed] stream_id: usize,
+        #[indexed] proposal_id: u64,
+        #[indexed] recipient: &ManagedAddress,
+        amount: &BigUint,
+    );
+
+    #[event("streamClaimed")]
+    fn stream_claimed_event(
+        &self,
+        #[indexed] stream_id: usize,
+        #[indexed] recipient: &ManagedAddress,
+        amount: &BigUint,
+    );
+
+    #[event("streamCancelled")]
+    fn stream_cancelled_event(
+        &self,
+        #[indexed] stream_id: usize,
+        #[indexed] proposal_id: u64,
+        returned_amount: &BigUint,
+    );
+
+    #[event("votingRewardFunded")]
+    fn voting_reward_funded_event(
+        &self,
+        #[indexed] proposal_id: u64,
+        #[indexed] funder: &ManagedAddress,
+        amount: &BigUint,
+    );
+
+    #[event("votingRewardClaimed")]
+    fn voting_reward_claimed_event(
+        &self,
+        #[indexed] proposal_id: u64,
+        #[indexed] voter: &ManagedAddress,
+        amount: &BigUint,
+    );
+
+    #[event("votingRewardRefunded")]
+    fn voting_reward_refunded_event(
+        &self,
+        #[indexed] proposal_id: u64,
+        #[indexed] funder: &ManagedAddress,
+        amount: &BigUint,
+    );
+
+    #[event("votingRewardSwept")]
+    fn voting_reward_swept_event(&self, #[indexed] proposal_id: u64, amount: &BigUint);
+
+    #[event("creatorBountyAttached")]
+    fn creator_bounty_attached_event(
+        &self,
+        #[indexed] proposal_id: u64,
+        #[indexed] creator: &ManagedAddress,
+        amount: &BigUint,
+    );
+
+    #[event("creatorBountyClaimed")]
+    fn creator_bounty_claimed_event(
+        &self,
+        #[indexed] proposal_id: u64,
+        #[indexed] voter: &ManagedAddress,
+        amount: &BigUint,
+    );
+
+    #[event("creatorBountyReclaimed")]
+    fn creator_bounty_reclaimed_event(
+        &self,
+        #[indexed] proposal_id: u64,
+        #[indexed] creator: &ManagedAddress,
+        amount: &BigUint,
+    );
+
+    #[event("predictionStaked")]
+    fn prediction_staked_event(
+        &self,
+        #[indexed] proposal_id: u64,
+        #[indexed] staker: &ManagedAddress,
+        #[indexed] option: usize,
+        amount: &BigUint,
+    );
+
+    #[event("predictionClaimed")]
+    fn prediction_claimed_event(
+        &self,
+        #[indexed] proposal_id: u64,
+        #[indexed] staker: &ManagedAddress,
+        amount: &BigUint,
+    );
+
+    #[event("contractUpgraded")]
+    fn contract_upgraded_event(&self, #[indexed] storage_version: u32);
+
+    #[event("storageMigrationProgress")]
+    fn storage_migration_progress_event(&self, #[indexed] migrated: u64, #[indexed] total: u64);
+
+    #[event("storageMigrated")]
+    fn storage_migrated_event(&self, #[indexed] storage_version: u32);
+
+    #[event("codeUpgradeExecuted")]
+    fn code_upgrade_executed_event(&self, #[indexed] proposal_id: u64, code_hash: &ManagedBuffer);
+
+    #[event("finalizationPoolFunded")]
+    fn finalization_pool_funded_event(&self, #[indexed] funder: &ManagedAddress, amount: &BigUint);
+
+    #[event("finalizationBountyPaid")]
+    fn finalization_bounty_paid_event(&self, #[indexed] recipient: &ManagedAddress, amount: &BigUint);
+
+    #[event("contractPaused")]
+    fn contract_paused_event(&self);
+
+    #[event("contractUnpaused")]
+    fn contract_unpaused_event(&self);
+
+    #[event("pauserAdded")]
+    fn pauser_added_event(&self, #[indexed] pauser: &ManagedAddress);
+
+    #[event("pauserRemoved")]
+    fn pauser_removed_event(&self, #[indexed] pauser: &ManagedAddress);
+
+    #[event("operationPaused")]
+    fn operation_paused_event(&self, #[indexed] scope: PauseScope, #[indexed] paused_until: u64);
+
+    #[event("operationUnpaused")]
+    fn operation_unpaused_event(&self, #[indexed] scope: PauseScope);
+
+    #[event("ownershipTransferred")]
+    fn ownership_transferred_event(
         &self, 
         #[indexed] old_owner: &ManagedAddress, 
         #[indexed] new_owner: &ManagedAddress
     );
 
+    #[event("categoryAdded")]
+    fn category_added_event(
+        &self,
+        #[indexed] category_id: usize,
+        #[indexed] quorum: u64,
+        #[indexed] threshold_bps: u64,
+        #[indexed] min_duration: u64,
+    );
+
+    #[event("categoryUpdated")]
+    fn category_updated_event(
+        &self,
+        #[indexed] category_id: usize,
+        #[indexed] quorum: u64,
+        #[indexed] threshold_bps: u64,
+        #[indexed] min_duration: u64,
+    );
+
+    #[event("spaceCreated")]
+    fn space_created_event(
+        &self,
+        #[indexed] space_id: usize,
+        #[indexed] admin: &ManagedAddress,
+        name: &ManagedBuffer,
+    );
+
+    #[event("spaceAdminAdded")]
+    fn space_admin_added_event(&self, #[indexed] space_id: usize, #[indexed] admin: &ManagedAddress);
+
+    #[event("spaceAdminRemoved")]
+    fn space_admin_removed_event(&self, #[indexed] space_id: usize, #[indexed] admin: &ManagedAddress);
+
+    #[event("spaceVoterRegistered")]
+    fn space_voter_registered_event(&self, #[indexed] space_id: usize, #[indexed] voter: &ManagedAddress);
+
+    #[event("spaceVoterRemoved")]
+    fn space_voter_removed_event(&self, #[indexed] space_id: usize, #[indexed] voter: &ManagedAddress);
+
+    #[event("spaceConfigUpdated")]
+    fn space_config_updated_event(
+        &self,
+        #[indexed] space_id: usize,
+        #[indexed] quorum: u64,
+        #[indexed] threshold_bps: u64,
+        #[indexed] min_duration: u64,
+    );
+
+    #[event("spaceProposalCreated")]
+    fn space_proposal_created_event(
+        &self,
+        #[indexed] space_id: usize,
+        #[indexed] local_id: u64,
+        #[indexed] proposal_id: u64,
+    );
+
     // ============= STORAGE MAPPERS =============
 
     #[storage_mapper("owner")]
@@ -280,6 +4824,13 @@ pub trait ChainBallotX {
     #[storage_mapper("isPaused")]
     fn is_paused(&self) -> SingleValueMapper<bool>;
 
+    #[storage_mapper("pausers")]
+    fn pausers(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    // Vazio enquanto a operação não está pausada; INDEFINITE_PAUSE sem prazo
+    #[storage_mapper("operationPausedUntil")]
+    fn operation_paused_until(&self, scope: PauseScope) -> SingleValueMapper<u64>;
+
     #[storage_mapper("totalProposals")]
     fn total_proposals(&self) -> SingleValueMapper<u64>;
 
@@ -292,6 +4843,7 @@ pub trait ChainBallotX {
     #[storage_mapper("hasVoted")]
     fn has_voted(&self, proposal_id: u64, voter: &ManagedAddress) -> SingleValueMapper<bool>;
 
+    // Layout legado (versão 1): lido apenas para propostas ainda não migradas
     #[storage_mapper("proposalTitle")]
     fn proposal_title(&self, proposal_id: u64) -> SingleValueMapper<ManagedBuffer>;
 
@@ -309,4 +4861,327 @@ pub trait ChainBallotX {
 
     #[storage_mapper("proposalActive")]
     fn proposal_active(&self, proposal_id: u64) -> SingleValueMapper<bool>;
-}
\ No newline at end of file
+
+    #[storage_mapper("activeProposals")]
+    fn active_proposals(&self) -> UnorderedSetMapper<u64>;
+
+    #[storage_mapper("proposalOptions")]
+    fn proposal_options(&self, proposal_id: u64) -> VecMapper<ManagedBuffer>;
+
+    #[storage_mapper("proposalOptionVotes")]
+    fn proposal_option_votes(&self, proposal_id: u64, option: usize) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("proposalDocument")]
+    fn proposal_document(&self, proposal_id: u64) -> SingleValueMapper<ProposalDocument<Self::Api>>;
+
+    // Layout legado (versão 1)
+    #[storage_mapper("proposalDuration")]
+    fn proposal_duration(&self, proposal_id: u64) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("proposalStart")]
+    fn proposal_start(&self, proposal_id: u64) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("proposalDraft")]
+    fn proposal_draft(&self, proposal_id: u64) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("proposalEditCount")]
+    fn proposal_edit_count(&self, proposal_id: u64) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("proposalCategory")]
+    fn proposal_category(&self, proposal_id: u64) -> SingleValueMapper<usize>;
+
+    #[storage_mapper("proposalQuorum")]
+    fn proposal_quorum(&self, proposal_id: u64) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("proposalThresholdBps")]
+    fn proposal_threshold_bps(&self, proposal_id: u64) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("proposalTags")]
+    fn proposal_tags(&self, proposal_id: u64) -> SingleValueMapper<ManagedVec<ManagedBuffer>>;
+
+    #[storage_mapper("proposalParent")]
+    fn proposal_parent(&self, proposal_id: u64) -> SingleValueMapper<Option<u64>>;
+
+    #[storage_mapper("proposalAmendments")]
+    fn proposal_amendments(&self, proposal_id: u64) -> VecMapper<u64>;
+
+    #[storage_mapper("proposalExtensionCount")]
+    fn proposal_extension_count(&self, proposal_id: u64) -> SingleValueMapper<usize>;
+
+    #[storage_mapper("proposalOutcome")]
+    fn proposal_outcome(&self, proposal_id: u64) -> SingleValueMapper<ProposalOutcome>;
+
+    #[storage_mapper("proposalVoters")]
+    fn proposal_voters(&self, proposal_id: u64) -> VecMapper<ManagedAddress>;
+
+    #[storage_mapper("proposalsByCreator")]
+    fn proposals_by_creator(&self, creator: &ManagedAddress) -> VecMapper<u64>;
+
+    #[storage_mapper("votesByVoter")]
+    fn votes_by_voter(&self, voter: &ManagedAddress) -> VecMapper<VoteRecord>;
+
+    #[storage_mapper("antiSnipingConfig")]
+    fn anti_sniping_config(&self) -> SingleValueMapper<AntiSnipingConfig>;
+
+    #[storage_mapper("finalizationBounty")]
+    fn finalization_bounty(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("finalizationPool")]
+    fn finalization_pool(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("categories")]
+    fn categories(&self) -> VecMapper<Category<Self::Api>>;
+
+    #[storage_mapper("proposalsByCategory")]
+    fn proposals_by_category(&self, category_id: usize) -> UnorderedSetMapper<u64>;
+
+    #[storage_mapper("proposalBallotType")]
+    fn proposal_ballot_type(&self, proposal_id: u64) -> SingleValueMapper<BallotType>;
+
+    #[storage_mapper("proposalScoreRange")]
+    fn proposal_score_range(&self, proposal_id: u64) -> SingleValueMapper<ScoreRange>;
+
+    #[storage_mapper("proposalSeats")]
+    fn proposal_seats(&self, proposal_id: u64) -> SingleValueMapper<usize>;
+
+    #[storage_mapper("proposalElected")]
+    fn proposal_elected(&self, proposal_id: u64) -> VecMapper<usize>;
+
+    #[storage_mapper("rankedBallots")]
+    fn ranked_ballots(&self, proposal_id: u64) -> VecMapper<ManagedBuffer>;
+
+    #[storage_mapper("rankedRounds")]
+    fn ranked_rounds(&self, proposal_id: u64) -> VecMapper<ManagedVec<u64>>;
+
+    #[storage_mapper("rankedCountState")]
+    fn ranked_count_state(&self, proposal_id: u64) -> SingleValueMapper<RankedCountState<Self::Api>>;
+
+    #[storage_mapper("governanceToken")]
+    fn governance_token(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    #[storage_mapper("convictionConfig")]
+    fn conviction_config(&self) -> SingleValueMapper<ConvictionConfig>;
+
+    #[storage_mapper("convictionPool")]
+    fn conviction_pool(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("convictionTotalStaked")]
+    fn conviction_total_staked(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("convictionRequest")]
+    fn conviction_request(&self, proposal_id: u64) -> SingleValueMapper<ConvictionRequest<Self::Api>>;
+
+    #[storage_mapper("convictionValue")]
+    fn conviction_value(&self, proposal_id: u64) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("convictionLastUpdate")]
+    fn conviction_last_update(&self, proposal_id: u64) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("convictionThresholdReached")]
+    fn conviction_threshold_reached(&self, proposal_id: u64) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("convictionStaked")]
+    fn conviction_staked(&self, proposal_id: u64) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("convictionStake")]
+    fn conviction_stake(&self, proposal_id: u64, staker: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("proposalVotingPower")]
+    fn proposal_voting_power(&self, proposal_id: u64) -> SingleValueMapper<VotingPowerMode>;
+
+    #[storage_mapper("proposalTotalWeight")]
+    fn proposal_total_weight(&self, proposal_id: u64) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("voteEscrowConfig")]
+    fn vote_escrow_config(&self) -> SingleValueMapper<VoteEscrowConfig<Self::Api>>;
+
+    #[storage_mapper("voteEscrowCheckpoints")]
+    fn vote_escrow_checkpoints(&self, user: &ManagedAddress) -> VecMapper<VoteEscrowLock<Self::Api>>;
+
+    #[storage_mapper("voteEscrowTotalLocked")]
+    fn vote_escrow_total_locked(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("nftGate")]
+    fn nft_gate(&self, proposal_id: u64) -> SingleValueMapper<NftGate<Self::Api>>;
+
+    #[storage_mapper("nftNonceUsed")]
+    fn nft_nonce_used(&self, proposal_id: u64, nonce: u64) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("nftDeposits")]
+    fn nft_deposits(&self, proposal_id: u64, voter: &ManagedAddress) -> VecMapper<EsdtTokenPayment>;
+
+    #[storage_mapper("treasuryBalance")]
+    fn treasury_balance(
+        &self,
+        token_id: &EgldOrEsdtTokenIdentifier,
+        token_nonce: u64,
+    ) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("treasuryAssets")]
+    fn treasury_assets(&self) -> UnorderedSetMapper<(EgldOrEsdtTokenIdentifier, u64)>;
+
+    #[storage_mapper("treasurySpend")]
+    fn treasury_spend(&self, proposal_id: u64) -> SingleValueMapper<TreasurySpend<Self::Api>>;
+
+    #[storage_mapper("treasurySpendExecuted")]
+    fn treasury_spend_executed(&self, proposal_id: u64) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("governanceActionQuorum")]
+    fn governance_action_quorum(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("governanceActionThresholdBps")]
+    fn governance_action_threshold_bps(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("spendVesting")]
+    fn spend_vesting(&self, proposal_id: u64) -> SingleValueMapper<VestingSchedule>;
+
+    #[storage_mapper("vestingStreams")]
+    fn vesting_streams(&self) -> VecMapper<VestingStream<Self::Api>>;
+
+    #[storage_mapper("streamsByRecipient")]
+    fn streams_by_recipient(&self, recipient: &ManagedAddress) -> VecMapper<usize>;
+
+    #[storage_mapper("streamCancellation")]
+    fn stream_cancellation(&self, proposal_id: u64) -> SingleValueMapper<usize>;
+
+    #[storage_mapper("voterWeight")]
+    fn voter_weight(&self, proposal_id: u64, voter: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("votingRewardPool")]
+    fn voting_reward_pool(&self, proposal_id: u64) -> SingleValueMapper<EgldOrEsdtTokenPayment>;
+
+    #[storage_mapper("votingRewardDistributed")]
+    fn voting_reward_distributed(&self, proposal_id: u64) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("votingRewardContribution")]
+    fn voting_reward_contribution(
+        &self,
+        proposal_id: u64,
+        funder: &ManagedAddress,
+    ) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("votingRewardClaimed")]
+    fn voting_reward_claimed(
+        &self,
+        proposal_id: u64,
+        voter: &ManagedAddress,
+    ) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("defaultBountyRule")]
+    fn default_bounty_rule(&self) -> SingleValueMapper<BountyRule>;
+
+    #[storage_mapper("creatorBounty")]
+    fn creator_bounty(&self, proposal_id: u64) -> SingleValueMapper<EgldOrEsdtTokenPayment>;
+
+    #[storage_mapper("creatorBountyRule")]
+    fn creator_bounty_rule(&self, proposal_id: u64) -> SingleValueMapper<BountyRule>;
+
+    #[storage_mapper("creatorBountyDistributed")]
+    fn creator_bounty_distributed(&self, proposal_id: u64) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("creatorBountyClaimed")]
+    fn creator_bounty_claimed(
+        &self,
+        proposal_id: u64,
+        voter: &ManagedAddress,
+    ) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("voterChoice")]
+    fn voter_choice(&self, proposal_id: u64, voter: &ManagedAddress) -> SingleValueMapper<usize>;
+
+    #[storage_mapper("predictionFeeBps")]
+    fn prediction_fee_bps(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("predictionMarket")]
+    fn prediction_market(&self, proposal_id: u64) -> SingleValueMapper<PredictionMarket<Self::Api>>;
+
+    #[storage_mapper("predictionPosition")]
+    fn prediction_position(
+        &self,
+        proposal_id: u64,
+        staker: &ManagedAddress,
+    ) -> SingleValueMapper<PredictionPosition<Self::Api>>;
+
+    #[storage_mapper("predictionOptionStaked")]
+    fn prediction_option_staked(&self, proposal_id: u64, option: usize) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("predictionTotalStaked")]
+    fn prediction_total_staked(&self, proposal_id: u64) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("predictionWinningOption")]
+    fn prediction_winning_option(&self, proposal_id: u64) -> SingleValueMapper<Option<usize>>;
+
+    #[storage_mapper("predictionFeeCollected")]
+    fn prediction_fee_collected(&self, proposal_id: u64) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("predictionPaidWinnings")]
+    fn prediction_paid_winnings(&self, proposal_id: u64) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("predictionClaimedStake")]
+    fn prediction_claimed_stake(&self, proposal_id: u64) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("predictionClaimed")]
+    fn prediction_claimed(
+        &self,
+        proposal_id: u64,
+        staker: &ManagedAddress,
+    ) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("storageVersion")]
+    fn storage_version(&self) -> SingleValueMapper<u32>;
+
+    #[storage_mapper("migrationCursor")]
+    fn migration_cursor(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("proposalHeader")]
+    fn proposal_header(&self, proposal_id: u64) -> SingleValueMapper<ProposalHeader<Self::Api>>;
+
+    #[storage_mapper("codeUpgrade")]
+    fn code_upgrade(&self, proposal_id: u64) -> SingleValueMapper<CodeUpgrade<Self::Api>>;
+
+    #[storage_mapper("codeUpgradeExecuted")]
+    fn code_upgrade_executed(&self, proposal_id: u64) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("spaceNames")]
+    fn space_names(&self) -> VecMapper<ManagedBuffer>;
+
+    #[storage_mapper("spaceAdmins")]
+    fn space_admins(&self, space_id: usize) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("spaceVoters")]
+    fn space_voters(&self, space_id: usize) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("spaceConfig")]
+    fn space_config(&self, space_id: usize) -> SingleValueMapper<SpaceConfig>;
+
+    // Índice i + 1 guarda o id global da proposta i do espaço
+    #[storage_mapper("spaceProposals")]
+    fn space_proposals(&self, space_id: usize) -> VecMapper<u64>;
+
+    #[storage_mapper("spaceProposalCount")]
+    fn space_proposal_count(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("spaceUserProposalCount")]
+    fn space_user_proposal_count(&self, space_id: usize, user: &ManagedAddress) -> SingleValueMapper<usize>;
+
+    #[storage_mapper("spaceTotalVotes")]
+    fn space_total_votes(&self, space_id: usize) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("proposalSpace")]
+    fn proposal_space(&self, proposal_id: u64) -> SingleValueMapper<usize>;
+}
+
+// a^t em ponto fixo (escala CONVICTION_SCALE), por exponenciação rápida
+fn decay_power(decay: u64, mut periods: u64) -> u64 {
+    let scale = CONVICTION_SCALE as u128;
+    let mut base = decay as u128;
+    let mut result = scale;
+    while periods > 0 && result > 0 {
+        if periods & 1 == 1 {
+            result = result * base / scale;
+        }
+        base = base * base / scale;
+        periods >>= 1;
+    }
+    result as u64
+}
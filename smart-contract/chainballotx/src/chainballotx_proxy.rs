@@ -53,6 +53,25 @@ where
     }
 }
 
+#[rustfmt::skip]
+impl<Env, From, To, Gas> ChainBallotXProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    pub fn upgrade(
+        self,
+    ) -> TxTypedUpgrade<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_upgrade()
+            .original_result()
+    }
+}
+
 #[rustfmt::skip]
 impl<Env, From, To, Gas> ChainBallotXProxyMethods<Env, From, To, Gas>
 where
@@ -80,6 +99,61 @@ where
             .original_result()
     }
 
+    pub fn add_pauser<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        pauser: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("add_pauser")
+            .argument(&pauser)
+            .original_result()
+    }
+
+    pub fn remove_pauser<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        pauser: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("remove_pauser")
+            .argument(&pauser)
+            .original_result()
+    }
+
+    pub fn pause_operation<
+        Arg0: ProxyArg<PauseScope>,
+        Arg1: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        scope: Arg0,
+        unpause_at: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("pause_operation")
+            .argument(&scope)
+            .argument(&unpause_at)
+            .original_result()
+    }
+
+    pub fn unpause_operation<
+        Arg0: ProxyArg<PauseScope>,
+    >(
+        self,
+        scope: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unpause_operation")
+            .argument(&scope)
+            .original_result()
+    }
+
     pub fn transfer_ownership<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
     >(
@@ -93,178 +167,2889 @@ where
             .original_result()
     }
 
+    pub fn add_category<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<u64>,
+    >(
+        self,
+        name: Arg0,
+        quorum: Arg1,
+        threshold_bps: Arg2,
+        min_duration: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("add_category")
+            .argument(&name)
+            .argument(&quorum)
+            .argument(&threshold_bps)
+            .argument(&min_duration)
+            .original_result()
+    }
+
+    pub fn update_category<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<u64>,
+    >(
+        self,
+        category_id: Arg0,
+        quorum: Arg1,
+        threshold_bps: Arg2,
+        min_duration: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("update_category")
+            .argument(&category_id)
+            .argument(&quorum)
+            .argument(&threshold_bps)
+            .argument(&min_duration)
+            .original_result()
+    }
+
+    pub fn set_anti_sniping<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<usize>,
+    >(
+        self,
+        window: Arg0,
+        extension: Arg1,
+        max_extensions: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_anti_sniping")
+            .argument(&window)
+            .argument(&extension)
+            .argument(&max_extensions)
+            .original_result()
+    }
+
+    pub fn extend_deadline<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        proposal_id: Arg0,
+        extension: Arg1,
+        reason: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("extend_deadline")
+            .argument(&proposal_id)
+            .argument(&extension)
+            .argument(&reason)
+            .original_result()
+    }
+
+    pub fn set_finalization_bounty<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        amount: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_finalization_bounty")
+            .argument(&amount)
+            .original_result()
+    }
+
+    pub fn set_governance_token<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_governance_token")
+            .argument(&token_id)
+            .original_result()
+    }
+
+    pub fn set_conviction_config<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<u64>,
+    >(
+        self,
+        decay_bps: Arg0,
+        period: Arg1,
+        max_ratio_bps: Arg2,
+        weight_bps: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_conviction_config")
+            .argument(&decay_bps)
+            .argument(&period)
+            .argument(&max_ratio_bps)
+            .argument(&weight_bps)
+            .original_result()
+    }
+
+    pub fn set_vote_escrow_config<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        max_lock_duration: Arg0,
+        power_unit: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_vote_escrow_config")
+            .argument(&max_lock_duration)
+            .argument(&power_unit)
+            .original_result()
+    }
+
+    pub fn set_default_bounty_rule<
+        Arg0: ProxyArg<BountyRule>,
+    >(
+        self,
+        rule: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_default_bounty_rule")
+            .argument(&rule)
+            .original_result()
+    }
+
+    pub fn set_governance_action_rules<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        quorum: Arg0,
+        threshold_bps: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_governance_action_rules")
+            .argument(&quorum)
+            .argument(&threshold_bps)
+            .original_result()
+    }
+
+    pub fn set_prediction_fee<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        fee_bps: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_prediction_fee")
+            .argument(&fee_bps)
+            .original_result()
+    }
+
+    pub fn migrate_storage<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        batch_size: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("migrate_storage")
+            .argument(&batch_size)
+            .original_result()
+    }
+
     pub fn create_proposal<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
         Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
         Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>>,
     >(
         self,
         title: Arg0,
         description: Arg1,
         duration: Arg2,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        options: Arg3,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
         self.wrapped_tx
-            .payment(NotPayable)
             .raw_call("create_proposal")
             .argument(&title)
             .argument(&description)
             .argument(&duration)
+            .argument(&options)
             .original_result()
     }
 
-    pub fn vote<
+    pub fn create_draft<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>>,
+    >(
+        self,
+        title: Arg0,
+        description: Arg1,
+        duration: Arg2,
+        options: Arg3,
+    ) -> TxTypedCall<Env, From, To, (), Gas, u64> {
+        self.wrapped_tx
+            .raw_call("create_draft")
+            .argument(&title)
+            .argument(&description)
+            .argument(&duration)
+            .argument(&options)
+            .original_result()
+    }
+
+    pub fn create_amendment<
         Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
     >(
         self,
-        proposal_id: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        parent_id: Arg0,
+        title: Arg1,
+        description: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("vote")
-            .argument(&proposal_id)
+            .raw_call("create_amendment")
+            .argument(&parent_id)
+            .argument(&title)
+            .argument(&description)
             .original_result()
     }
 
-    pub fn cancel_proposal<
+    pub fn edit_proposal<
         Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg3: ProxyArg<MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>>,
     >(
         self,
         proposal_id: Arg0,
+        title: Arg1,
+        description: Arg2,
+        options: Arg3,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("cancel_proposal")
+            .raw_call("edit_proposal")
             .argument(&proposal_id)
+            .argument(&title)
+            .argument(&description)
+            .argument(&options)
             .original_result()
     }
 
-    pub fn get_proposal_title<
+    pub fn set_ballot_type<
         Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<BallotType>,
     >(
         self,
         proposal_id: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedBuffer<Env::Api>> {
+        ballot_type: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("get_proposal_title")
+            .raw_call("set_ballot_type")
             .argument(&proposal_id)
+            .argument(&ballot_type)
             .original_result()
     }
 
-    pub fn get_proposal_description<
+    pub fn set_score_range<
         Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<usize>,
     >(
         self,
         proposal_id: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedBuffer<Env::Api>> {
+        min_score: Arg1,
+        max_score: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("get_proposal_description")
+            .raw_call("set_score_range")
             .argument(&proposal_id)
+            .argument(&min_score)
+            .argument(&max_score)
             .original_result()
     }
 
-    pub fn get_proposal_creator<
+    pub fn set_proposal_seats<
         Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<usize>,
     >(
         self,
         proposal_id: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        seats: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("get_proposal_creator")
+            .raw_call("set_proposal_seats")
             .argument(&proposal_id)
+            .argument(&seats)
             .original_result()
     }
 
-    pub fn get_proposal_vote_count<
+    pub fn set_voting_power<
         Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<VotingPowerMode>,
     >(
         self,
         proposal_id: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        mode: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("get_proposal_vote_count")
+            .raw_call("set_voting_power")
             .argument(&proposal_id)
+            .argument(&mode)
             .original_result()
     }
 
-    pub fn get_proposal_deadline<
+    pub fn set_nft_gate<
         Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<TokenIdentifier<Env::Api>>,
+        Arg2: ProxyArg<bool>,
     >(
         self,
         proposal_id: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        collection: Arg1,
+        return_immediately: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("get_proposal_deadline")
+            .raw_call("set_nft_gate")
             .argument(&proposal_id)
+            .argument(&collection)
+            .argument(&return_immediately)
             .original_result()
     }
 
-    pub fn is_proposal_active<
+    pub fn set_treasury_spend<
         Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg2: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg3: ProxyArg<u64>,
+        Arg4: ProxyArg<BigUint<Env::Api>>,
     >(
         self,
         proposal_id: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        recipient: Arg1,
+        token_id: Arg2,
+        token_nonce: Arg3,
+        amount: Arg4,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("is_proposal_active")
+            .raw_call("set_treasury_spend")
             .argument(&proposal_id)
+            .argument(&recipient)
+            .argument(&token_id)
+            .argument(&token_nonce)
+            .argument(&amount)
             .original_result()
     }
 
-    pub fn get_total_proposals(
+    pub fn set_spend_vesting<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<u64>,
+    >(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        proposal_id: Arg0,
+        start: Arg1,
+        cliff: Arg2,
+        end: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("get_total_proposals")
+            .raw_call("set_spend_vesting")
+            .argument(&proposal_id)
+            .argument(&start)
+            .argument(&cliff)
+            .argument(&end)
             .original_result()
     }
 
-    pub fn get_total_votes(
+    pub fn set_stream_cancellation<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<usize>,
+    >(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        proposal_id: Arg0,
+        stream_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("get_total_votes")
+            .raw_call("set_stream_cancellation")
+            .argument(&proposal_id)
+            .argument(&stream_id)
             .original_result()
     }
 
-    pub fn has_user_voted_on_proposal<
+    pub fn set_code_upgrade<
         Arg0: ProxyArg<u64>,
-        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<CodeMetadata>,
     >(
         self,
         proposal_id: Arg0,
-        user_address: Arg1,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        code_hash: Arg1,
+        code_metadata: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("has_user_voted_on_proposal")
+            .raw_call("set_code_upgrade")
             .argument(&proposal_id)
-            .argument(&user_address)
+            .argument(&code_hash)
+            .argument(&code_metadata)
             .original_result()
     }
 
-    pub fn is_contract_paused(
+    pub fn set_creator_bounty_rule<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<BountyRule>,
+    >(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        proposal_id: Arg0,
+        rule: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("is_contract_paused")
+            .raw_call("set_creator_bounty_rule")
+            .argument(&proposal_id)
+            .argument(&rule)
             .original_result()
     }
 
-    pub fn get_owner(
+    pub fn set_prediction_market<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        proposal_id: Arg0,
+        token_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("get_owner")
+            .raw_call("set_prediction_market")
+            .argument(&proposal_id)
+            .argument(&token_id)
             .original_result()
     }
+
+    pub fn publish_proposal<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("publish_proposal")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn vote<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<MultiValueEncoded<Env::Api, usize>>,
+    >(
+        self,
+        proposal_id: Arg0,
+        ballot: Arg1,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("vote")
+            .argument(&proposal_id)
+            .argument(&ballot)
+            .original_result()
+    }
+
+    pub fn set_proposal_document<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<HashAlgorithm>,
+        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        proposal_id: Arg0,
+        hash_algorithm: Arg1,
+        content_hash: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_proposal_document")
+            .argument(&proposal_id)
+            .argument(&hash_algorithm)
+            .argument(&content_hash)
+            .original_result()
+    }
+
+    pub fn set_proposal_category<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        proposal_id: Arg0,
+        category_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_proposal_category")
+            .argument(&proposal_id)
+            .argument(&category_id)
+            .original_result()
+    }
+
+    pub fn set_proposal_tags<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>>,
+    >(
+        self,
+        proposal_id: Arg0,
+        tags: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_proposal_tags")
+            .argument(&proposal_id)
+            .argument(&tags)
+            .original_result()
+    }
+
+    pub fn cancel_proposal<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("cancel_proposal")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn finalize_proposal<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("finalize_proposal")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn fund_finalization_pool(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("fund_finalization_pool")
+            .original_result()
+    }
+
+    pub fn create_conviction_proposal<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg3: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        title: Arg0,
+        description: Arg1,
+        beneficiary: Arg2,
+        requested_amount: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("create_conviction_proposal")
+            .argument(&title)
+            .argument(&description)
+            .argument(&beneficiary)
+            .argument(&requested_amount)
+            .original_result()
+    }
+
+    pub fn fund_conviction_pool(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("fund_conviction_pool")
+            .original_result()
+    }
+
+    pub fn stake_conviction<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("stake_conviction")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn withdraw_conviction<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        proposal_id: Arg0,
+        amount: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("withdraw_conviction")
+            .argument(&proposal_id)
+            .argument(&amount)
+            .original_result()
+    }
+
+    pub fn move_conviction<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        from_proposal_id: Arg0,
+        to_proposal_id: Arg1,
+        amount: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("move_conviction")
+            .argument(&from_proposal_id)
+            .argument(&to_proposal_id)
+            .argument(&amount)
+            .original_result()
+    }
+
+    pub fn execute_conviction_proposal<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("execute_conviction_proposal")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn lock_tokens<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        unlock_time: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("lock_tokens")
+            .argument(&unlock_time)
+            .original_result()
+    }
+
+    pub fn extend_lock<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        unlock_time: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("extend_lock")
+            .argument(&unlock_time)
+            .original_result()
+    }
+
+    pub fn withdraw_lock(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("withdraw_lock")
+            .original_result()
+    }
+
+    pub fn claim_voting_nfts<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("claim_voting_nfts")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn deposit_to_treasury(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("deposit_to_treasury")
+            .original_result()
+    }
+
+    pub fn execute_treasury_spend<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("execute_treasury_spend")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn claim_stream<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        stream_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("claim_stream")
+            .argument(&stream_id)
+            .original_result()
+    }
+
+    pub fn cancel_stream<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("cancel_stream")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn fund_voting_reward<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("fund_voting_reward")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn claim_voting_reward<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("claim_voting_reward")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn refund_voting_reward<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("refund_voting_reward")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn sweep_voting_reward<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("sweep_voting_reward")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn claim_creator_bounty<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("claim_creator_bounty")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn reclaim_creator_bounty<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("reclaim_creator_bounty")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn execute_code_upgrade<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        proposal_id: Arg0,
+        code: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("execute_code_upgrade")
+            .argument(&proposal_id)
+            .argument(&code)
+            .original_result()
+    }
+
+    pub fn stake_prediction<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        proposal_id: Arg0,
+        option: Arg1,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("stake_prediction")
+            .argument(&proposal_id)
+            .argument(&option)
+            .original_result()
+    }
+
+    pub fn claim_prediction<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("claim_prediction")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn create_space<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        name: Arg0,
+        admin: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("create_space")
+            .argument(&name)
+            .argument(&admin)
+            .original_result()
+    }
+
+    pub fn add_space_admin<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        space_id: Arg0,
+        admin: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("add_space_admin")
+            .argument(&space_id)
+            .argument(&admin)
+            .original_result()
+    }
+
+    pub fn remove_space_admin<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        space_id: Arg0,
+        admin: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("remove_space_admin")
+            .argument(&space_id)
+            .argument(&admin)
+            .original_result()
+    }
+
+    pub fn register_space_voters<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        space_id: Arg0,
+        voters: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("register_space_voters")
+            .argument(&space_id)
+            .argument(&voters)
+            .original_result()
+    }
+
+    pub fn remove_space_voters<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        space_id: Arg0,
+        voters: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("remove_space_voters")
+            .argument(&space_id)
+            .argument(&voters)
+            .original_result()
+    }
+
+    pub fn set_space_config<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<u64>,
+    >(
+        self,
+        space_id: Arg0,
+        quorum: Arg1,
+        threshold_bps: Arg2,
+        min_duration: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_space_config")
+            .argument(&space_id)
+            .argument(&quorum)
+            .argument(&threshold_bps)
+            .argument(&min_duration)
+            .original_result()
+    }
+
+    pub fn create_space_proposal<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg3: ProxyArg<u64>,
+        Arg4: ProxyArg<MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>>,
+    >(
+        self,
+        space_id: Arg0,
+        title: Arg1,
+        description: Arg2,
+        duration: Arg3,
+        options: Arg4,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("create_space_proposal")
+            .argument(&space_id)
+            .argument(&title)
+            .argument(&description)
+            .argument(&duration)
+            .argument(&options)
+            .original_result()
+    }
+
+    pub fn vote_in_space<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<MultiValueEncoded<Env::Api, usize>>,
+    >(
+        self,
+        space_id: Arg0,
+        local_id: Arg1,
+        ballot: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("vote_in_space")
+            .argument(&space_id)
+            .argument(&local_id)
+            .argument(&ballot)
+            .original_result()
+    }
+
+    pub fn cancel_space_proposal<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        space_id: Arg0,
+        local_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("cancel_space_proposal")
+            .argument(&space_id)
+            .argument(&local_id)
+            .original_result()
+    }
+
+    pub fn finalize_space_proposal<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        space_id: Arg0,
+        local_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("finalize_space_proposal")
+            .argument(&space_id)
+            .argument(&local_id)
+            .original_result()
+    }
+
+    pub fn get_proposal_title<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedBuffer<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_proposal_title")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn get_proposal_description<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedBuffer<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_proposal_description")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn get_proposal_creator<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_proposal_creator")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn get_proposal_vote_count<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_proposal_vote_count")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn get_proposal_deadline<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_proposal_deadline")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn is_proposal_active<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("is_proposal_active")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn get_total_proposals(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_total_proposals")
+            .original_result()
+    }
+
+    pub fn get_default_proposal_count(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_default_proposal_count")
+            .original_result()
+    }
+
+    pub fn get_total_votes(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_total_votes")
+            .original_result()
+    }
+
+    pub fn has_user_voted_on_proposal<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        proposal_id: Arg0,
+        user_address: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("has_user_voted_on_proposal")
+            .argument(&proposal_id)
+            .argument(&user_address)
+            .original_result()
+    }
+
+    pub fn is_contract_paused(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("is_contract_paused")
+            .original_result()
+    }
+
+    pub fn is_operation_paused<
+        Arg0: ProxyArg<PauseScope>,
+    >(
+        self,
+        scope: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("is_operation_paused")
+            .argument(&scope)
+            .original_result()
+    }
+
+    pub fn get_pause_state(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, PauseState> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_pause_state")
+            .original_result()
+    }
+
+    pub fn get_pausers(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_pausers")
+            .original_result()
+    }
+
+    pub fn is_pauser<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("is_pauser")
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn get_owner(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_owner")
+            .original_result()
+    }
+
+    pub fn get_proposal<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Proposal<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_proposal")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn get_proposal_status<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ProposalStatus> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_proposal_status")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn get_proposals<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        from: Arg0,
+        count: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, Proposal<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_proposals")
+            .argument(&from)
+            .argument(&count)
+            .original_result()
+    }
+
+    pub fn get_proposals_by_status<
+        Arg0: ProxyArg<ProposalStatus>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<usize>,
+    >(
+        self,
+        status: Arg0,
+        from: Arg1,
+        count: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, Proposal<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_proposals_by_status")
+            .argument(&status)
+            .argument(&from)
+            .argument(&count)
+            .original_result()
+    }
+
+    pub fn get_active_proposal_count(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_active_proposal_count")
+            .original_result()
+    }
+
+    pub fn get_proposal_options<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_proposal_options")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn get_option_vote_count<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        proposal_id: Arg0,
+        option: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_option_vote_count")
+            .argument(&proposal_id)
+            .argument(&option)
+            .original_result()
+    }
+
+    pub fn get_proposal_voters<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<usize>,
+    >(
+        self,
+        proposal_id: Arg0,
+        from: Arg1,
+        count: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_proposal_voters")
+            .argument(&proposal_id)
+            .argument(&from)
+            .argument(&count)
+            .original_result()
+    }
+
+    pub fn get_proposal_voter_count<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_proposal_voter_count")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn get_proposal_document<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<ProposalDocument<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_proposal_document")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn get_proposal_amendments<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_proposal_amendments")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn get_amendment_parent<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_amendment_parent")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn get_proposal_outcome<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ProposalOutcome> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_proposal_outcome")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn get_finalization_bounty(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_finalization_bounty")
+            .original_result()
+    }
+
+    pub fn get_finalization_pool(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_finalization_pool")
+            .original_result()
+    }
+
+    pub fn get_anti_sniping_config(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, AntiSnipingConfig> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_anti_sniping_config")
+            .original_result()
+    }
+
+    pub fn get_proposal_extension_count<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_proposal_extension_count")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn get_ballot_type<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BallotType> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_ballot_type")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn get_score_range<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<ScoreRange>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_score_range")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn get_proposal_seats<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_proposal_seats")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn get_elected_options<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, usize>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_elected_options")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn get_ranked_rounds<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedVec<Env::Api, u64>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_ranked_rounds")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn get_ranked_count_state<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<RankedCountState<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_ranked_count_state")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn get_governance_token(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<TokenIdentifier<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_governance_token")
+            .original_result()
+    }
+
+    pub fn get_conviction_config(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<ConvictionConfig>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_conviction_config")
+            .original_result()
+    }
+
+    pub fn get_conviction_pool(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_conviction_pool")
+            .original_result()
+    }
+
+    pub fn get_conviction_total_staked(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_conviction_total_staked")
+            .original_result()
+    }
+
+    pub fn get_conviction<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_conviction")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn get_conviction_threshold<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<BigUint<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_conviction_threshold")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn get_conviction_staked<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_conviction_staked")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn get_conviction_stake<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        proposal_id: Arg0,
+        staker: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_conviction_stake")
+            .argument(&proposal_id)
+            .argument(&staker)
+            .original_result()
+    }
+
+    pub fn get_vote_escrow_config(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<VoteEscrowConfig<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_vote_escrow_config")
+            .original_result()
+    }
+
+    pub fn get_vote_escrow_lock<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<VoteEscrowLock<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_vote_escrow_lock")
+            .argument(&user)
+            .original_result()
+    }
+
+    pub fn get_voting_power<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_voting_power")
+            .argument(&user)
+            .original_result()
+    }
+
+    pub fn get_voting_power_at<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        user: Arg0,
+        timestamp: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_voting_power_at")
+            .argument(&user)
+            .argument(&timestamp)
+            .original_result()
+    }
+
+    pub fn get_unlock_time<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_unlock_time")
+            .argument(&user)
+            .original_result()
+    }
+
+    pub fn get_vote_escrow_total_locked(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_vote_escrow_total_locked")
+            .original_result()
+    }
+
+    pub fn get_proposal_voting_power<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, VotingPowerMode> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_proposal_voting_power")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn get_nft_gate<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<NftGate<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_nft_gate")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn is_nft_nonce_used<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+        nonce: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("is_nft_nonce_used")
+            .argument(&proposal_id)
+            .argument(&nonce)
+            .original_result()
+    }
+
+    pub fn get_nft_deposits<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        proposal_id: Arg0,
+        voter: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, EsdtTokenPayment<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_nft_deposits")
+            .argument(&proposal_id)
+            .argument(&voter)
+            .original_result()
+    }
+
+    pub fn get_proposal_snapshot<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_proposal_snapshot")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn get_treasury_balance<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        token_id: Arg0,
+        token_nonce: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_treasury_balance")
+            .argument(&token_id)
+            .argument(&token_nonce)
+            .original_result()
+    }
+
+    pub fn get_treasury_balances(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, EgldOrEsdtTokenPayment<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_treasury_balances")
+            .original_result()
+    }
+
+    pub fn get_governance_action_rules(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<MultiValue2<u64, u64>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_governance_action_rules")
+            .original_result()
+    }
+
+    pub fn get_treasury_spend<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<TreasurySpend<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_treasury_spend")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn is_treasury_spend_executed<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("is_treasury_spend_executed")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn get_spend_vesting<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<VestingSchedule>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_spend_vesting")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn get_stream_count(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_stream_count")
+            .original_result()
+    }
+
+    pub fn get_stream<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        stream_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, VestingStream<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_stream")
+            .argument(&stream_id)
+            .original_result()
+    }
+
+    pub fn get_stream_vested<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        stream_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_stream_vested")
+            .argument(&stream_id)
+            .original_result()
+    }
+
+    pub fn get_stream_claimable<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        stream_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_stream_claimable")
+            .argument(&stream_id)
+            .original_result()
+    }
+
+    pub fn get_streams_by_recipient<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        recipient: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, usize>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_streams_by_recipient")
+            .argument(&recipient)
+            .original_result()
+    }
+
+    pub fn get_voting_reward_pool<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<EgldOrEsdtTokenPayment<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_voting_reward_pool")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn get_voting_reward_distributed<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_voting_reward_distributed")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn get_voting_reward_deadline<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_voting_reward_deadline")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn get_voting_reward<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        proposal_id: Arg0,
+        voter: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_voting_reward")
+            .argument(&proposal_id)
+            .argument(&voter)
+            .original_result()
+    }
+
+    pub fn has_claimed_voting_reward<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        proposal_id: Arg0,
+        voter: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("has_claimed_voting_reward")
+            .argument(&proposal_id)
+            .argument(&voter)
+            .original_result()
+    }
+
+    pub fn get_voting_reward_contribution<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        proposal_id: Arg0,
+        funder: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_voting_reward_contribution")
+            .argument(&proposal_id)
+            .argument(&funder)
+            .original_result()
+    }
+
+    pub fn get_default_bounty_rule(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BountyRule> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_default_bounty_rule")
+            .original_result()
+    }
+
+    pub fn get_creator_bounty<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<EgldOrEsdtTokenPayment<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_creator_bounty")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn get_creator_bounty_rule<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BountyRule> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_creator_bounty_rule")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn get_creator_bounty_balance<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_creator_bounty_balance")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn get_creator_bounty_share<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        proposal_id: Arg0,
+        voter: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_creator_bounty_share")
+            .argument(&proposal_id)
+            .argument(&voter)
+            .original_result()
+    }
+
+    pub fn has_claimed_creator_bounty<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        proposal_id: Arg0,
+        voter: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("has_claimed_creator_bounty")
+            .argument(&proposal_id)
+            .argument(&voter)
+            .original_result()
+    }
+
+    pub fn get_prediction_fee(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_prediction_fee")
+            .original_result()
+    }
+
+    pub fn get_prediction_market<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<PredictionMarket<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_prediction_market")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn get_prediction_pools<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, BigUint<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_prediction_pools")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn get_prediction_total_staked<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_prediction_total_staked")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn get_prediction_position<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        proposal_id: Arg0,
+        staker: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<PredictionPosition<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_prediction_position")
+            .argument(&proposal_id)
+            .argument(&staker)
+            .original_result()
+    }
+
+    pub fn get_prediction_winning_option<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<usize>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_prediction_winning_option")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn get_prediction_payout<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        proposal_id: Arg0,
+        staker: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_prediction_payout")
+            .argument(&proposal_id)
+            .argument(&staker)
+            .original_result()
+    }
+
+    pub fn get_code_upgrade<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<CodeUpgrade<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_code_upgrade")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn get_code_upgrade_eta<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_code_upgrade_eta")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn is_code_upgrade_executed<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("is_code_upgrade_executed")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn get_storage_version(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u32> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_storage_version")
+            .original_result()
+    }
+
+    pub fn get_migration_progress(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue3<u32, u64, u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_migration_progress")
+            .original_result()
+    }
+
+    pub fn get_categories(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, Category<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_categories")
+            .original_result()
+    }
+
+    pub fn get_category<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        category_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Category<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_category")
+            .argument(&category_id)
+            .original_result()
+    }
+
+    pub fn get_proposals_by_category<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<usize>,
+    >(
+        self,
+        category_id: Arg0,
+        from: Arg1,
+        count: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, Proposal<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_proposals_by_category")
+            .argument(&category_id)
+            .argument(&from)
+            .argument(&count)
+            .original_result()
+    }
+
+    pub fn get_category_proposal_count<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        category_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_category_proposal_count")
+            .argument(&category_id)
+            .original_result()
+    }
+
+    pub fn get_proposal_tags<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_proposal_tags")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn get_space_count(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_space_count")
+            .original_result()
+    }
+
+    pub fn get_space<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        space_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Space<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_space")
+            .argument(&space_id)
+            .original_result()
+    }
+
+    pub fn get_space_admins<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        space_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_space_admins")
+            .argument(&space_id)
+            .original_result()
+    }
+
+    pub fn is_space_admin<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        space_id: Arg0,
+        address: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("is_space_admin")
+            .argument(&space_id)
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn is_space_voter<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        space_id: Arg0,
+        address: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("is_space_voter")
+            .argument(&space_id)
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn get_space_voters<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<usize>,
+    >(
+        self,
+        space_id: Arg0,
+        from: Arg1,
+        count: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_space_voters")
+            .argument(&space_id)
+            .argument(&from)
+            .argument(&count)
+            .original_result()
+    }
+
+    pub fn get_space_config<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        space_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, SpaceConfig> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_space_config")
+            .argument(&space_id)
+            .original_result()
+    }
+
+    pub fn get_space_total_proposals<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        space_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_space_total_proposals")
+            .argument(&space_id)
+            .original_result()
+    }
+
+    pub fn get_space_total_votes<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        space_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_space_total_votes")
+            .argument(&space_id)
+            .original_result()
+    }
+
+    pub fn get_space_proposal<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        space_id: Arg0,
+        local_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Proposal<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_space_proposal")
+            .argument(&space_id)
+            .argument(&local_id)
+            .original_result()
+    }
+
+    pub fn get_space_proposals<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<usize>,
+    >(
+        self,
+        space_id: Arg0,
+        from: Arg1,
+        count: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, Proposal<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_space_proposals")
+            .argument(&space_id)
+            .argument(&from)
+            .argument(&count)
+            .original_result()
+    }
+
+    pub fn get_space_proposal_id<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        space_id: Arg0,
+        local_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_space_proposal_id")
+            .argument(&space_id)
+            .argument(&local_id)
+            .original_result()
+    }
+
+    pub fn get_proposal_space<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_proposal_space")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn get_proposals_by_creator<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<usize>,
+    >(
+        self,
+        creator: Arg0,
+        from: Arg1,
+        count: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, Proposal<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_proposals_by_creator")
+            .argument(&creator)
+            .argument(&from)
+            .argument(&count)
+            .original_result()
+    }
+
+    pub fn get_creator_proposal_count<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        creator: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_creator_proposal_count")
+            .argument(&creator)
+            .original_result()
+    }
+
+    pub fn get_votes_by_voter<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<usize>,
+    >(
+        self,
+        voter: Arg0,
+        from: Arg1,
+        count: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, VoteRecord>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_votes_by_voter")
+            .argument(&voter)
+            .argument(&from)
+            .argument(&count)
+            .original_result()
+    }
+
+    pub fn get_voter_vote_count<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        voter: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_voter_vote_count")
+            .argument(&voter)
+            .original_result()
+    }
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct Proposal<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub id: u64,
+    pub title: ManagedBuffer<Api>,
+    pub description: ManagedBuffer<Api>,
+    pub creator: ManagedAddress<Api>,
+    pub deadline: u64,
+    pub vote_count: u64,
+    pub status: ProposalStatus,
+    pub options: ManagedVec<Api, ManagedBuffer<Api>>,
+    pub option_votes: ManagedVec<Api, u64>,
+    pub document: Option<ProposalDocument<Api>>,
+    pub category_id: usize,
+    pub tags: ManagedVec<Api, ManagedBuffer<Api>>,
+    pub edit_count: u64,
+    pub parent_id: Option<u64>,
+    pub outcome: Option<ProposalOutcome>,
+    pub ballot_type: BallotType,
+    pub score_range: Option<ScoreRange>,
+    pub seats: usize,
+    pub conviction_request: Option<ConvictionRequest<Api>>,
+    pub voting_power: VotingPowerMode,
+    pub nft_gate: Option<NftGate<Api>>,
+    pub treasury_spend: Option<TreasurySpend<Api>>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ProposalStatus {
+    Active,
+    Ended,
+    Cancelled,
+    Draft,
+    Passed,
+    Rejected,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct VoteRecord {
+    pub proposal_id: u64,
+    pub choice: usize,
+    pub timestamp: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum HashAlgorithm {
+    Sha256,
+    Keccak256,
+    Blake2b256,
+    IpfsCid,
+    ArweaveTx,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct ProposalDocument<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub hash_algorithm: HashAlgorithm,
+    pub content_hash: ManagedBuffer<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct Category<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub id: usize,
+    pub name: ManagedBuffer<Api>,
+    pub quorum: u64,
+    pub threshold_bps: u64,
+    pub min_duration: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ProposalOutcome {
+    pub winning_option: usize,
+    pub winning_votes: u64,
+    pub total_votes: u64,
+    pub quorum_reached: bool,
+    pub threshold_reached: bool,
+    pub passed: bool,
+    pub adopted_amendment: Option<u64>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct AntiSnipingConfig {
+    pub window: u64,
+    pub extension: u64,
+    pub max_extensions: usize,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BallotType {
+    SingleChoice,
+    RankedChoice,
+    Approval,
+    Score,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ScoreRange {
+    pub min: usize,
+    pub max: usize,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct RankedCountState<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub round: usize,
+    pub next_ballot: usize,
+    pub eliminated: u32,
+    pub elected: u32,
+    pub exhausted: u64,
+    pub counts: ManagedVec<Api, u64>,
+    pub transfer_factors: ManagedVec<Api, u64>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct ConvictionRequest<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub beneficiary: ManagedAddress<Api>,
+    pub amount: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ConvictionConfig {
+    pub decay_bps: u64,
+    pub period: u64,
+    pub max_ratio_bps: u64,
+    pub weight_bps: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum VotingPowerMode {
+    OnePerAddress,
+    VoteEscrow,
+    Nft,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct VoteEscrowConfig<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub max_lock_duration: u64,
+    pub power_unit: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct VoteEscrowLock<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub amount: BigUint<Api>,
+    pub unlock_time: u64,
+    pub locked_at: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct NftGate<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub collection: TokenIdentifier<Api>,
+    pub return_immediately: bool,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct TreasurySpend<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub recipient: ManagedAddress<Api>,
+    pub token_id: EgldOrEsdtTokenIdentifier<Api>,
+    pub token_nonce: u64,
+    pub amount: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Debug)]
+pub struct VestingSchedule {
+    pub start: u64,
+    pub cliff: u64,
+    pub end: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct VestingStream<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub proposal_id: u64,
+    pub recipient: ManagedAddress<Api>,
+    pub token_id: EgldOrEsdtTokenIdentifier<Api>,
+    pub token_nonce: u64,
+    pub amount: BigUint<Api>,
+    pub schedule: VestingSchedule,
+    pub claimed: BigUint<Api>,
+    pub cancelled_at: Option<u64>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BountyRule {
+    AllVoters,
+    WinningVoters,
+    ReturnToCreator,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct PredictionMarket<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub token_id: EgldOrEsdtTokenIdentifier<Api>,
+    pub fee_bps: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct PredictionPosition<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub option: usize,
+    pub amount: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct CodeUpgrade<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub code_hash: ManagedBuffer<Api>,
+    pub code_metadata: CodeMetadata,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct SpaceConfig {
+    pub quorum: u64,
+    pub threshold_bps: u64,
+    pub min_duration: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct Space<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub id: usize,
+    pub name: ManagedBuffer<Api>,
+    pub config: SpaceConfig,
+    pub admin_count: usize,
+    pub voter_count: usize,
+    pub total_proposals: u64,
+    pub total_votes: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PauseScope {
+    Creation,
+    Voting,
+    Cancellation,
+    Execution,
+    Treasury,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct OperationPause {
+    pub paused: bool,
+    pub unpause_at: Option<u64>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct PauseState {
+    pub contract_paused: bool,
+    pub creation: OperationPause,
+    pub voting: OperationPause,
+    pub cancellation: OperationPause,
+    pub execution: OperationPause,
+    pub treasury: OperationPause,
 }
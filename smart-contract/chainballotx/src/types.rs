@@ -0,0 +1,405 @@
+// types.rs
+// Localização: contracts/types.rs
+
+use multiversx_sc::derive_imports::*;
+use multiversx_sc::imports::*;
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ProposalStatus {
+    Active,
+    Ended,
+    Cancelled,
+    Draft,
+    Passed,
+    Rejected,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct Proposal<M: ManagedTypeApi> {
+    pub id: u64,
+    pub title: ManagedBuffer<M>,
+    pub description: ManagedBuffer<M>,
+    pub creator: ManagedAddress<M>,
+    pub deadline: u64,
+    pub vote_count: u64,
+    pub status: ProposalStatus,
+    pub options: ManagedVec<M, ManagedBuffer<M>>,
+    pub option_votes: ManagedVec<M, u64>,
+    pub document: Option<ProposalDocument<M>>,
+    pub category_id: usize,
+    pub tags: ManagedVec<M, ManagedBuffer<M>>,
+    pub edit_count: u64,
+    pub parent_id: Option<u64>,
+    pub outcome: Option<ProposalOutcome>,
+    pub ballot_type: BallotType,
+    pub score_range: Option<ScoreRange>,
+    pub seats: usize,
+    pub conviction_request: Option<ConvictionRequest<M>>,
+    pub voting_power: VotingPowerMode,
+    pub nft_gate: Option<NftGate<M>>,
+    pub treasury_spend: Option<TreasurySpend<M>>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct VoteRecord {
+    pub proposal_id: u64,
+    pub choice: usize,
+    pub timestamp: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum HashAlgorithm {
+    Sha256,
+    Keccak256,
+    Blake2b256,
+    IpfsCid,
+    ArweaveTx,
+}
+
+impl HashAlgorithm {
+    // Digests são armazenados em bytes brutos; CIDs e ids do Arweave como texto
+    pub fn is_valid_hash(&self, hash: &[u8]) -> bool {
+        match self {
+            HashAlgorithm::Sha256 | HashAlgorithm::Keccak256 | HashAlgorithm::Blake2b256 => {
+                hash.len() == 32
+            },
+            HashAlgorithm::IpfsCid => is_valid_cid(hash),
+            HashAlgorithm::ArweaveTx => {
+                hash.len() == 43
+                    && hash
+                        .iter()
+                        .all(|c| c.is_ascii_alphanumeric() || *c == b'-' || *c == b'_')
+            },
+        }
+    }
+}
+
+// CIDv0: "Qm" + base58 (46 caracteres). CIDv1: multibase base32 ("b" + minúsculas e 2-7)
+fn is_valid_cid(cid: &[u8]) -> bool {
+    if cid.len() == 46 && cid.starts_with(b"Qm") {
+        return cid
+            .iter()
+            .all(|c| c.is_ascii_alphanumeric() && !matches!(c, b'0' | b'O' | b'I' | b'l'));
+    }
+
+    if cid.len() < 2 || cid[0] != b'b' {
+        return false;
+    }
+    let mut bytes = [0u8; MAX_CID_BYTES];
+    match decode_base32(&cid[1..], &mut bytes) {
+        Some(len) => is_valid_cid_v1(&bytes[..len]),
+        None => false,
+    }
+}
+
+const MAX_CID_BYTES: usize = 64;
+
+// versão 1 + codec + multihash (código do hash, tamanho, digest)
+fn is_valid_cid_v1(bytes: &[u8]) -> bool {
+    let Some((version, rest)) = read_varint(bytes) else {
+        return false;
+    };
+    let Some((_codec, rest)) = read_varint(rest) else {
+        return false;
+    };
+    let Some((hash_code, rest)) = read_varint(rest) else {
+        return false;
+    };
+    let Some((digest_len, digest)) = read_varint(rest) else {
+        return false;
+    };
+
+    // sha2-256, keccak-256 e blake2b-256: digests de 32 bytes
+    let expected_len = match hash_code {
+        0x12 | 0x1b | 0xb220 => 32,
+        _ => return false,
+    };
+    version == 1 && digest_len == expected_len && digest.len() == expected_len as usize
+}
+
+fn read_varint(bytes: &[u8]) -> Option<(u64, &[u8])> {
+    let mut value = 0u64;
+    for (index, byte) in bytes.iter().enumerate().take(9) {
+        value |= ((byte & 0x7f) as u64) << (7 * index);
+        if byte & 0x80 == 0 {
+            return Some((value, &bytes[index + 1..]));
+        }
+    }
+    None
+}
+
+// Base32 RFC 4648 minúsculo, sem padding; retorna o número de bytes decodificados
+fn decode_base32(input: &[u8], output: &mut [u8; MAX_CID_BYTES]) -> Option<usize> {
+    let mut buffer = 0u32;
+    let mut bits = 0u32;
+    let mut len = 0usize;
+    for c in input.iter() {
+        let value = match c {
+            b'a'..=b'z' => c - b'a',
+            b'2'..=b'7' => c - b'2' + 26,
+            _ => return None,
+        };
+        buffer = (buffer << 5) | value as u32;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            if len == MAX_CID_BYTES {
+                return None;
+            }
+            output[len] = (buffer >> bits) as u8;
+            len += 1;
+            buffer &= (1 << bits) - 1;
+        }
+    }
+    // Bits restantes são só preenchimento e precisam ser zero
+    if buffer != 0 {
+        return None;
+    }
+    Some(len)
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct ProposalDocument<M: ManagedTypeApi> {
+    pub hash_algorithm: HashAlgorithm,
+    pub content_hash: ManagedBuffer<M>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct Category<M: ManagedTypeApi> {
+    pub id: usize,
+    pub name: ManagedBuffer<M>,
+    pub quorum: u64,
+    pub threshold_bps: u64,
+    pub min_duration: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ProposalOutcome {
+    pub winning_option: usize,
+    pub winning_votes: u64,
+    pub total_votes: u64,
+    pub quorum_reached: bool,
+    pub threshold_reached: bool,
+    pub passed: bool,
+    pub adopted_amendment: Option<u64>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct AntiSnipingConfig {
+    pub window: u64,
+    pub extension: u64,
+    pub max_extensions: usize,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BallotType {
+    SingleChoice,
+    RankedChoice,
+    Approval,
+    Score,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ScoreRange {
+    pub min: usize,
+    pub max: usize,
+}
+
+// Progresso da apuração ranqueada (IRV ou STV) entre chamadas de finalização
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct RankedCountState<M: ManagedTypeApi> {
+    pub round: usize,
+    pub next_ballot: usize,
+    pub eliminated: u32,
+    pub elected: u32,
+    pub exhausted: u64,
+    pub counts: ManagedVec<M, u64>,
+    pub transfer_factors: ManagedVec<M, u64>,
+}
+
+// `decay_bps`: fração da convicção mantida a cada período.
+// `max_ratio_bps`: fração máxima do pool que uma proposta pode pedir.
+// `weight_bps`: peso do limiar (ρ na fórmula de convicção).
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ConvictionConfig {
+    pub decay_bps: u64,
+    pub period: u64,
+    pub max_ratio_bps: u64,
+    pub weight_bps: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct ConvictionRequest<M: ManagedTypeApi> {
+    pub beneficiary: ManagedAddress<M>,
+    pub amount: BigUint<M>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum VotingPowerMode {
+    OnePerAddress,
+    VoteEscrow,
+    Nft,
+}
+
+// NFTs usados no voto voltam na mesma transação ou ficam retidos até o fim da votação
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct NftGate<M: ManagedTypeApi> {
+    pub collection: TokenIdentifier<M>,
+    pub return_immediately: bool,
+}
+
+// `power_unit`: quantidade de poder de voto equivalente a um voto na apuração
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct VoteEscrowConfig<M: ManagedTypeApi> {
+    pub max_lock_duration: u64,
+    pub power_unit: BigUint<M>,
+}
+
+// Cada alteração do lock gera um checkpoint em `locked_at`
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct VoteEscrowLock<M: ManagedTypeApi> {
+    pub amount: BigUint<M>,
+    pub unlock_time: u64,
+    pub locked_at: u64,
+}
+
+// Transferência executada pela tesouraria quando a proposta é aprovada com a opção 0
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct TreasurySpend<M: ManagedTypeApi> {
+    pub recipient: ManagedAddress<M>,
+    pub token_id: EgldOrEsdtTokenIdentifier<M>,
+    pub token_nonce: u64,
+    pub amount: BigUint<M>,
+}
+
+// Cronograma de liberação linear com cliff (timestamps absolutos)
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Debug)]
+pub struct VestingSchedule {
+    pub start: u64,
+    pub cliff: u64,
+    pub end: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct VestingStream<M: ManagedTypeApi> {
+    pub proposal_id: u64,
+    pub recipient: ManagedAddress<M>,
+    pub token_id: EgldOrEsdtTokenIdentifier<M>,
+    pub token_nonce: u64,
+    pub amount: BigUint<M>,
+    pub schedule: VestingSchedule,
+    pub claimed: BigUint<M>,
+    pub cancelled_at: Option<u64>,
+}
+
+// Destino da recompensa depositada pelo criador na criação da proposta
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BountyRule {
+    AllVoters,
+    WinningVoters,
+    ReturnToCreator,
+}
+
+// Apostas sobre o resultado de uma enquete não vinculante
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct PredictionMarket<M: ManagedTypeApi> {
+    pub token_id: EgldOrEsdtTokenIdentifier<M>,
+    pub fee_bps: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct PredictionPosition<M: ManagedTypeApi> {
+    pub option: usize,
+    pub amount: BigUint<M>,
+}
+
+// Layout de armazenamento 2: campos fixos da proposta agrupados em uma única entrada
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct ProposalHeader<M: ManagedTypeApi> {
+    pub title: ManagedBuffer<M>,
+    pub description: ManagedBuffer<M>,
+    pub creator: ManagedAddress<M>,
+    pub duration: u64,
+}
+
+// Novo código do próprio contrato, identificado pelo sha256 do wasm
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct CodeUpgrade<M: ManagedTypeApi> {
+    pub code_hash: ManagedBuffer<M>,
+    pub code_metadata: CodeMetadata,
+}
+
+// Regras aplicadas às propostas criadas dentro de um espaço
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct SpaceConfig {
+    pub quorum: u64,
+    pub threshold_bps: u64,
+    pub min_duration: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct Space<M: ManagedTypeApi> {
+    pub id: usize,
+    pub name: ManagedBuffer<M>,
+    pub config: SpaceConfig,
+    pub admin_count: usize,
+    pub voter_count: usize,
+    pub total_proposals: u64,
+    pub total_votes: u64,
+}
+
+// Grupos de operações que podem ser pausados de forma independente
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PauseScope {
+    Creation,
+    Voting,
+    Cancellation,
+    Execution,
+    Treasury,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct OperationPause {
+    pub paused: bool,
+    pub unpause_at: Option<u64>, // None enquanto pausada sem prazo
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct PauseState {
+    pub contract_paused: bool,
+    pub creation: OperationPause,
+    pub voting: OperationPause,
+    pub cancellation: OperationPause,
+    pub execution: OperationPause,
+    pub treasury: OperationPause,
+}
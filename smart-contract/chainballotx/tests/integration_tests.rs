@@ -1,4 +1,4 @@
-use multiversx_sc::types::ManagedBuffer;
+use multiversx_sc::types::{BigUint, CodeMetadata, EgldOrEsdtTokenIdentifier, ManagedAddress, ManagedBuffer};
 use multiversx_sc_scenario::*;
 use multiversx_sc_scenario::scenario_model::*;
 use multiversx_sc_scenario::imports::{
@@ -6,11 +6,18 @@ use multiversx_sc_scenario::imports::{
     FilePath,                 // Para especificar o caminho do arquivo WASM
     ExpectValue,              // Para verificar valores esperados
     ExpectMessage,            // Para verificar mensagens de erro
+    ReturnsResult,            // Para ler o retorno de views
+    OptionalValue,            // Para argumentos opcionais
+    MultiValueEncoded,        // Para argumentos variáveis (opções)
+    StaticApi,                // API usada pelos tipos gerenciados nos testes
     TestAddress,              // Para criar endereços de teste
     TestSCAddress,            // Para criar endereços de smart contract de teste
+    TestTokenIdentifier,      // Para o token de governança
+    TestEsdtTransfer,         // Para pagamentos em ESDT
+    UserBuiltinProxy,         // Para a troca de dono do contrato (ChangeOwnerAddress)
 };
 use chainballotx::*;
-use chainballotx_proxy::ChainBallotXProxy;
+use chainballotx_proxy::{BallotType, BountyRule, ChainBallotXProxy, HashAlgorithm, PauseScope, ProposalStatus, VotingPowerMode};
 
 mod chainballotx_tests {
     use super::*;
@@ -26,6 +33,8 @@ mod chainballotx_tests {
     const NEW_OWNER_ADDRESS: TestAddress = TestAddress::new("new_owner");
     const OTHER_USER_ADDRESS: TestAddress = TestAddress::new("other_user");
     const CONTRACT_ADDRESS: TestSCAddress = TestSCAddress::new("contract");
+    const GOV_TOKEN: TestTokenIdentifier = TestTokenIdentifier::new("GOV-123456");
+    const NFT_COLLECTION: TestTokenIdentifier = TestTokenIdentifier::new("NFT-123456");
 
     #[test]
     fn test_init() {
@@ -74,6 +83,7 @@ mod chainballotx_tests {
                 ManagedBuffer::new_from_bytes(b"Proposta de Teste"),
                 ManagedBuffer::new_from_bytes(b"Esta e uma descricao de teste"),
                 86400u64,
+                no_options(),
             )
             .run();
 
@@ -123,6 +133,7 @@ mod chainballotx_tests {
                 ManagedBuffer::new_from_bytes(b""),
                 ManagedBuffer::new_from_bytes(b"Descricao valida"),
                 86400u64,
+                no_options(),
             )
             .with_result(ExpectMessage("Title cannot be empty"))
             .run();
@@ -136,6 +147,7 @@ mod chainballotx_tests {
                 ManagedBuffer::new_from_bytes(b"Titulo valido"),
                 ManagedBuffer::new_from_bytes(b""),
                 86400u64,
+                no_options(),
             )
             .with_result(ExpectMessage("Description cannot be empty"))
             .run();
@@ -149,6 +161,7 @@ mod chainballotx_tests {
                 ManagedBuffer::new_from_bytes(b"Titulo"),
                 ManagedBuffer::new_from_bytes(b"Descricao"),
                 1800u64,
+                no_options(),
             )
             .with_result(ExpectMessage("Duration too short"))
             .run();
@@ -184,6 +197,7 @@ mod chainballotx_tests {
                 ManagedBuffer::new_from_bytes(b"Proposta de Votacao"),
                 ManagedBuffer::new_from_bytes(b"Teste de sistema de votacao"),
                 86400u64,
+                no_options(),
             )
             .run();
 
@@ -192,7 +206,7 @@ mod chainballotx_tests {
             .from(VOTER1_ADDRESS)
             .to(CONTRACT_ADDRESS)
             .typed(ChainBallotXProxy)
-            .vote(0u64)
+            .vote(0u64, no_ballot())
             .run();
 
         world
@@ -224,7 +238,7 @@ mod chainballotx_tests {
             .from(VOTER2_ADDRESS)
             .to(CONTRACT_ADDRESS)
             .typed(ChainBallotXProxy)
-            .vote(0u64)
+            .vote(0u64, no_ballot())
             .run();
 
         world
@@ -270,7 +284,7 @@ mod chainballotx_tests {
             .from(VOTER_ADDRESS)
             .to(CONTRACT_ADDRESS)
             .typed(ChainBallotXProxy)
-            .vote(999u64)
+            .vote(999u64, no_ballot())
             .with_result(ExpectMessage("Proposal does not exist"))
             .run();
 
@@ -284,6 +298,7 @@ mod chainballotx_tests {
                 ManagedBuffer::new_from_bytes(b"Proposta"),
                 ManagedBuffer::new_from_bytes(b"Descricao"),
                 86400u64,
+                no_options(),
             )
             .run();
 
@@ -301,7 +316,7 @@ mod chainballotx_tests {
             .from(VOTER_ADDRESS)
             .to(CONTRACT_ADDRESS)
             .typed(ChainBallotXProxy)
-            .vote(0u64)
+            .vote(0u64, no_ballot())
             .run();
 
         // Teste 3: Tentar votar novamente (deve falhar)
@@ -310,7 +325,7 @@ mod chainballotx_tests {
             .from(VOTER_ADDRESS)
             .to(CONTRACT_ADDRESS)
             .typed(ChainBallotXProxy)
-            .vote(0u64)
+            .vote(0u64, no_ballot())
             .with_result(ExpectMessage("Already voted on this proposal"))
             .run();
 
@@ -342,6 +357,7 @@ mod chainballotx_tests {
                 ManagedBuffer::new_from_bytes(b"Proposta Expirada"),
                 ManagedBuffer::new_from_bytes(b"Teste de expiracao"),
                 3600u64,
+                no_options(),
             )
             .run();
 
@@ -358,7 +374,7 @@ mod chainballotx_tests {
             .from(VOTER_ADDRESS)
             .to(CONTRACT_ADDRESS)
             .typed(ChainBallotXProxy)
-            .vote(1u64)
+            .vote(1u64, no_ballot())
             .with_result(ExpectMessage("Voting period ended"))
             .run();
     }
@@ -392,6 +408,7 @@ mod chainballotx_tests {
                 ManagedBuffer::new_from_bytes(b"Proposta Cancelavel"),
                 ManagedBuffer::new_from_bytes(b"Sera cancelada pelo criador"),
                 86400u64,
+                no_options(),
             )
             .run();
 
@@ -420,6 +437,7 @@ mod chainballotx_tests {
                 ManagedBuffer::new_from_bytes(b"Nova Proposta"),
                 ManagedBuffer::new_from_bytes(b"Para teste de autorizacao"),
                 86400u64,
+                no_options(),
             )
             .run();
 
@@ -488,6 +506,7 @@ mod chainballotx_tests {
                 ManagedBuffer::new_from_bytes(b"Teste"),
                 ManagedBuffer::new_from_bytes(b"Nao deve funcionar"),
                 86400u64,
+                no_options(),
             )
             .with_result(ExpectMessage("Contract is paused"))
             .run();
@@ -567,6 +586,7 @@ mod chainballotx_tests {
                     ManagedBuffer::new_from_bytes(title.as_bytes()),
                     ManagedBuffer::new_from_bytes(description.as_bytes()),
                     86400u64,
+                    no_options(),
                 )
                 .run();
         }
@@ -588,6 +608,7 @@ mod chainballotx_tests {
                 ManagedBuffer::new_from_bytes(b"Proposta 11"),
                 ManagedBuffer::new_from_bytes(b"Esta deve falhar"),
                 86400u64,
+                no_options(),
             )
             .with_result(ExpectMessage("Maximum proposals per user exceeded"))
             .run();
@@ -605,6 +626,7 @@ mod chainballotx_tests {
                     ManagedBuffer::new_from_bytes(title.as_bytes()),
                     ManagedBuffer::new_from_bytes(description.as_bytes()),
                     86400u64,
+                    no_options(),
                 )
                 .run();
         }
@@ -618,11 +640,6225 @@ mod chainballotx_tests {
                 ManagedBuffer::new_from_bytes(b"Proposta 11 Other"),
                 ManagedBuffer::new_from_bytes(b"Esta deve falhar"),
                 86400u64,
+                no_options(),
             )
             .with_result(ExpectMessage("Maximum proposals per user exceeded"))
             .run();
     }
 
+    #[test]
+    fn test_proposal_listing() {
+        let mut world = world();
+
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .put_account(OWNER_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .new_address(OWNER_ADDRESS, 1, CONTRACT_ADDRESS)
+            );
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .init()
+            .code(FilePath(WASM_PATH))
+            .run();
+
+        for i in 0..3 {
+            let title = format!("Proposta {}", i);
+
+            world
+                .tx()
+                .from(OWNER_ADDRESS)
+                .to(CONTRACT_ADDRESS)
+                .typed(ChainBallotXProxy)
+                .create_proposal(
+                    ManagedBuffer::new_from_bytes(title.as_bytes()),
+                    ManagedBuffer::new_from_bytes(b"Descricao"),
+                    86400u64,
+                    no_options(),
+                )
+                .run();
+        }
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .cancel_proposal(1u64)
+            .run();
+
+        let proposals: Vec<_> = world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_proposals(0u64, 10usize)
+            .returns(ReturnsResult)
+            .run()
+            .into_iter()
+            .collect();
+
+        assert_eq!(proposals.len(), 3);
+        assert_eq!(proposals[1].id, 1u64);
+        assert_eq!(proposals[1].status, ProposalStatus::Cancelled);
+
+        // Página limitada pelo total de propostas
+        let proposals: Vec<_> = world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_proposals(2u64, 10usize)
+            .returns(ReturnsResult)
+            .run()
+            .into_iter()
+            .collect();
+
+        assert_eq!(proposals.len(), 1);
+        assert_eq!(proposals[0].id, 2u64);
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_active_proposal_count()
+            .returns(ExpectValue(2usize))
+            .run();
+
+        let active: Vec<_> = world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_proposals_by_status(ProposalStatus::Active, 0usize, 10usize)
+            .returns(ReturnsResult)
+            .run()
+            .into_iter()
+            .collect();
+
+        assert_eq!(active.len(), 2);
+
+        let cancelled: Vec<_> = world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_proposals_by_status(ProposalStatus::Cancelled, 0usize, 10usize)
+            .returns(ReturnsResult)
+            .run()
+            .into_iter()
+            .collect();
+
+        assert_eq!(cancelled.len(), 1);
+        assert_eq!(cancelled[0].id, 1u64);
+
+        // Após o prazo, as propostas abertas passam a `Ended`
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .block_timestamp(90000u64)
+            );
+
+        let ended: Vec<_> = world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_proposals_by_status(ProposalStatus::Ended, 1usize, 10usize)
+            .returns(ReturnsResult)
+            .run()
+            .into_iter()
+            .collect();
+
+        assert_eq!(ended.len(), 1);
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_proposal_status(0u64)
+            .returns(ExpectValue(ProposalStatus::Ended))
+            .run();
+    }
+
+    #[test]
+    fn test_creator_and_voter_indexes() {
+        let mut world = world();
+
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .put_account(OWNER_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(USER_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(VOTER_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .new_address(OWNER_ADDRESS, 1, CONTRACT_ADDRESS)
+            );
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .init()
+            .code(FilePath(WASM_PATH))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .create_proposal(
+                ManagedBuffer::new_from_bytes(b"Proposta com Opcoes"),
+                ManagedBuffer::new_from_bytes(b"Escolha uma opcao"),
+                86400u64,
+                options(&["Sim", "Nao"]),
+            )
+            .run();
+
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .create_proposal(
+                ManagedBuffer::new_from_bytes(b"Proposta de Apoio"),
+                ManagedBuffer::new_from_bytes(b"Apoio simples"),
+                86400u64,
+                no_options(),
+            )
+            .run();
+
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .create_proposal(
+                ManagedBuffer::new_from_bytes(b"Opcao Unica"),
+                ManagedBuffer::new_from_bytes(b"Deve falhar"),
+                86400u64,
+                options(&["Sim"]),
+            )
+            .with_result(ExpectMessage("At least two options required"))
+            .run();
+
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .block_timestamp(100u64)
+            );
+
+        world
+            .tx()
+            .from(VOTER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .vote(0u64, ballot(&[5]))
+            .with_result(ExpectMessage("Invalid option"))
+            .run();
+
+        world
+            .tx()
+            .from(VOTER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .vote(0u64, ballot(&[1]))
+            .run();
+
+        world
+            .tx()
+            .from(VOTER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .vote(1u64, no_ballot())
+            .run();
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_option_vote_count(0u64, 1usize)
+            .returns(ExpectValue(1u64))
+            .run();
+
+        let created: Vec<_> = world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_proposals_by_creator(USER_ADDRESS.to_address(), 0usize, 10usize)
+            .returns(ReturnsResult)
+            .run()
+            .into_iter()
+            .collect();
+
+        assert_eq!(created.len(), 1);
+        assert_eq!(created[0].id, 1u64);
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_voter_vote_count(VOTER_ADDRESS.to_address())
+            .returns(ExpectValue(2usize))
+            .run();
+
+        let votes: Vec<_> = world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_votes_by_voter(VOTER_ADDRESS.to_address(), 0usize, 10usize)
+            .returns(ReturnsResult)
+            .run()
+            .into_iter()
+            .collect();
+
+        assert_eq!(votes.len(), 2);
+        assert_eq!(votes[0].proposal_id, 0u64);
+        assert_eq!(votes[0].choice, 1usize);
+        assert_eq!(votes[0].timestamp, 100u64);
+        assert_eq!(votes[1].proposal_id, 1u64);
+        assert_eq!(votes[1].choice, 0usize);
+
+        // Página fora do intervalo retorna vazio
+        let votes: Vec<_> = world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_votes_by_voter(VOTER_ADDRESS.to_address(), 5usize, 10usize)
+            .returns(ReturnsResult)
+            .run()
+            .into_iter()
+            .collect();
+
+        assert!(votes.is_empty());
+    }
+
+    #[test]
+    fn test_proposal_voters() {
+        let mut world = world();
+
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .put_account(OWNER_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(VOTER1_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(VOTER2_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .new_address(OWNER_ADDRESS, 1, CONTRACT_ADDRESS)
+            );
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .init()
+            .code(FilePath(WASM_PATH))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .create_proposal(
+                ManagedBuffer::new_from_bytes(b"Proposta Auditada"),
+                ManagedBuffer::new_from_bytes(b"Lista de votantes"),
+                86400u64,
+                no_options(),
+            )
+            .run();
+
+        for voter in [VOTER1_ADDRESS, VOTER2_ADDRESS] {
+            world
+                .tx()
+                .from(voter)
+                .to(CONTRACT_ADDRESS)
+                .typed(ChainBallotXProxy)
+                .vote(0u64, no_ballot())
+                .run();
+        }
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_proposal_voter_count(0u64)
+            .returns(ExpectValue(2usize))
+            .run();
+
+        let voters: Vec<_> = world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_proposal_voters(0u64, 1usize, 10usize)
+            .returns(ReturnsResult)
+            .run()
+            .into_iter()
+            .collect();
+
+        assert_eq!(voters.len(), 1);
+        assert_eq!(voters[0].to_address(), VOTER2_ADDRESS.to_address());
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_proposal_voter_count(7u64)
+            .with_result(ExpectMessage("Proposal does not exist"))
+            .run();
+    }
+
+    #[test]
+    fn test_proposal_document() {
+        let mut world = world();
+        let cid = b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .put_account(OWNER_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(USER_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(VOTER_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .new_address(OWNER_ADDRESS, 1, CONTRACT_ADDRESS)
+            );
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .init()
+            .code(FilePath(WASM_PATH))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .create_draft(
+                ManagedBuffer::new_from_bytes(b"Especificacao Tecnica"),
+                ManagedBuffer::new_from_bytes(b"Resumo da especificacao"),
+                86400u64,
+                no_options(),
+            )
+            .run();
+
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .set_proposal_document(0u64, HashAlgorithm::IpfsCid, ManagedBuffer::new_from_bytes(cid))
+            .with_result(ExpectMessage("Only creator can edit proposal"))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .set_proposal_document(0u64, HashAlgorithm::IpfsCid, ManagedBuffer::new_from_bytes(b"QmInvalido"))
+            .with_result(ExpectMessage("Invalid content hash"))
+            .run();
+
+        // CIDv1 precisa conter um multihash completo
+        for invalid_cid in [&b"bafy"[..], &b"bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbz"[..]] {
+            world
+                .tx()
+                .from(OWNER_ADDRESS)
+                .to(CONTRACT_ADDRESS)
+                .typed(ChainBallotXProxy)
+                .set_proposal_document(0u64, HashAlgorithm::IpfsCid, ManagedBuffer::new_from_bytes(invalid_cid))
+                .with_result(ExpectMessage("Invalid content hash"))
+                .run();
+        }
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .set_proposal_document(
+                0u64,
+                HashAlgorithm::IpfsCid,
+                ManagedBuffer::new_from_bytes(b"bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi"),
+            )
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .set_proposal_document(0u64, HashAlgorithm::Sha256, ManagedBuffer::new_from_bytes(&[1u8; 31]))
+            .with_result(ExpectMessage("Invalid content hash"))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .set_proposal_document(0u64, HashAlgorithm::IpfsCid, ManagedBuffer::new_from_bytes(cid))
+            .run();
+
+        let proposal = world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_proposal(0u64)
+            .returns(ReturnsResult)
+            .run();
+
+        let document = proposal.document.expect("document should be stored");
+        assert_eq!(document.hash_algorithm, HashAlgorithm::IpfsCid);
+        assert_eq!(document.content_hash, ManagedBuffer::new_from_bytes(cid));
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .publish_proposal(0u64)
+            .run();
+
+        // O documento fica congelado a partir da publicação
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .set_proposal_document(0u64, HashAlgorithm::Sha256, ManagedBuffer::new_from_bytes(&[1u8; 32]))
+            .with_result(ExpectMessage("Proposal is not a draft"))
+            .run();
+    }
+
+    #[test]
+    fn test_categories_and_tags() {
+        let mut world = world();
+
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .put_account(OWNER_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(USER_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .new_address(OWNER_ADDRESS, 1, CONTRACT_ADDRESS)
+            );
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .init()
+            .code(FilePath(WASM_PATH))
+            .run();
+
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .add_category(ManagedBuffer::new_from_bytes(b"Tesouraria"), 10u64, 6000u64, 86400u64)
+            .with_result(ExpectMessage("Only owner can call this function"))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .add_category(ManagedBuffer::new_from_bytes(b"Tesouraria"), 10u64, 6000u64, 172800u64)
+            .returns(ExpectValue(1usize))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .add_category(ManagedBuffer::new_from_bytes(b"Tecnica"), 5u64, 5000u64, 3600u64)
+            .returns(ExpectValue(2usize))
+            .run();
+
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .create_draft(
+                ManagedBuffer::new_from_bytes(b"Atualizar Nodes"),
+                ManagedBuffer::new_from_bytes(b"Proposta tecnica"),
+                86400u64,
+                no_options(),
+            )
+            .run();
+
+        // Categoria exige duração mínima de dois dias
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .set_proposal_category(0u64, 1usize)
+            .with_result(ExpectMessage("Duration too short for category"))
+            .run();
+
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .set_proposal_category(0u64, 9usize)
+            .with_result(ExpectMessage("Category does not exist"))
+            .run();
+
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .set_proposal_category(0u64, 2usize)
+            .run();
+
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .set_proposal_tags(0u64, options(&["infra", "nodes"]))
+            .run();
+
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .set_proposal_tags(0u64, options(&["a", "b", "c", "d", "e", "f"]))
+            .with_result(ExpectMessage("Too many tags"))
+            .run();
+
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .publish_proposal(0u64)
+            .run();
+
+        // Categoria e tags não mudam depois da publicação
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .set_proposal_category(0u64, 0usize)
+            .with_result(ExpectMessage("Proposal is not a draft"))
+            .run();
+
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .set_proposal_tags(0u64, options(&["outra"]))
+            .with_result(ExpectMessage("Proposal is not a draft"))
+            .run();
+
+        let technical: Vec<_> = world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_proposals_by_category(2usize, 0usize, 10usize)
+            .returns(ReturnsResult)
+            .run()
+            .into_iter()
+            .collect();
+
+        assert_eq!(technical.len(), 1);
+        assert_eq!(technical[0].category_id, 2usize);
+        assert_eq!(technical[0].tags.len(), 2);
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_category_proposal_count(0usize)
+            .returns(ExpectValue(0usize))
+            .run();
+
+        let category = world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_category(1usize)
+            .returns(ReturnsResult)
+            .run();
+
+        assert_eq!(category.quorum, 10u64);
+        assert_eq!(category.threshold_bps, 6000u64);
+
+        // Reclassificar não reordena as páginas das demais propostas
+        for _ in 0..3 {
+            world
+                .tx()
+                .from(OWNER_ADDRESS)
+                .to(CONTRACT_ADDRESS)
+                .typed(ChainBallotXProxy)
+                .create_draft(
+                    ManagedBuffer::new_from_bytes(b"Rascunho"),
+                    ManagedBuffer::new_from_bytes(b"Sem categoria"),
+                    86400u64,
+                    no_options(),
+                )
+                .run();
+        }
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .set_proposal_category(1u64, 2usize)
+            .run();
+
+        // Rascunhos só entram no índice da categoria ao serem publicados
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_category_proposal_count(0usize)
+            .returns(ExpectValue(0usize))
+            .run();
+
+        for proposal_id in 1u64..4 {
+            world
+                .tx()
+                .from(OWNER_ADDRESS)
+                .to(CONTRACT_ADDRESS)
+                .typed(ChainBallotXProxy)
+                .publish_proposal(proposal_id)
+                .run();
+        }
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_category_proposal_count(2usize)
+            .returns(ExpectValue(2usize))
+            .run();
+
+        let mut uncategorized = Vec::new();
+        for from in 0usize..2 {
+            let page: Vec<_> = world
+                .query()
+                .to(CONTRACT_ADDRESS)
+                .typed(ChainBallotXProxy)
+                .get_proposals_by_category(0usize, from, 1usize)
+                .returns(ReturnsResult)
+                .run()
+                .into_iter()
+                .collect();
+            uncategorized.extend(page.into_iter().map(|proposal| proposal.id));
+        }
+        assert_eq!(uncategorized, vec![2u64, 3u64]);
+    }
+
+    #[test]
+    fn test_draft_proposals() {
+        let mut world = world();
+
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .put_account(OWNER_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(USER_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(VOTER_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .new_address(OWNER_ADDRESS, 1, CONTRACT_ADDRESS)
+            );
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .init()
+            .code(FilePath(WASM_PATH))
+            .run();
+
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .create_draft(
+                ManagedBuffer::new_from_bytes(b"Rascunho com Erro"),
+                ManagedBuffer::new_from_bytes(b"Descricao com erro"),
+                86400u64,
+                options(&["Sim", "Nao"]),
+            )
+            .returns(ExpectValue(0u64))
+            .run();
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_proposal_status(0u64)
+            .returns(ExpectValue(ProposalStatus::Draft))
+            .run();
+
+        world
+            .tx()
+            .from(VOTER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .vote(0u64, ballot(&[0]))
+            .with_result(ExpectMessage("Proposal is not published"))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .edit_proposal(
+                0u64,
+                ManagedBuffer::new_from_bytes(b"Rascunho Corrigido"),
+                ManagedBuffer::new_from_bytes(b"Descricao corrigida"),
+                options(&["Sim", "Nao", "Abstencao"]),
+            )
+            .with_result(ExpectMessage("Only creator can edit proposal"))
+            .run();
+
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .edit_proposal(
+                0u64,
+                ManagedBuffer::new_from_bytes(b"Rascunho Corrigido"),
+                ManagedBuffer::new_from_bytes(b"Descricao corrigida"),
+                options(&["Sim", "Nao", "Abstencao"]),
+            )
+            .run();
+
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .block_timestamp(1000u64)
+            );
+
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .publish_proposal(0u64)
+            .run();
+
+        let proposal = world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_proposal(0u64)
+            .returns(ReturnsResult)
+            .run();
+
+        assert_eq!(proposal.title, ManagedBuffer::new_from_bytes(b"Rascunho Corrigido"));
+        assert_eq!(proposal.options.len(), 3);
+        assert_eq!(proposal.edit_count, 1u64);
+        assert_eq!(proposal.status, ProposalStatus::Active);
+        // O prazo conta a partir da publicação
+        assert_eq!(proposal.deadline, 1000u64 + 86400u64);
+
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .edit_proposal(
+                0u64,
+                ManagedBuffer::new_from_bytes(b"Depois de Publicar"),
+                ManagedBuffer::new_from_bytes(b"Nao deve funcionar"),
+                no_options(),
+            )
+            .with_result(ExpectMessage("Proposal is not a draft"))
+            .run();
+
+        world
+            .tx()
+            .from(VOTER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .vote(0u64, ballot(&[2]))
+            .run();
+    }
+
+    #[test]
+    fn test_proposal_amendments() {
+        let mut world = world();
+
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .put_account(OWNER_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(USER_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(VOTER1_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(VOTER2_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(OTHER_USER_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .new_address(OWNER_ADDRESS, 1, CONTRACT_ADDRESS)
+            );
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .init()
+            .code(FilePath(WASM_PATH))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .create_proposal(
+                ManagedBuffer::new_from_bytes(b"Orcamento Anual"),
+                ManagedBuffer::new_from_bytes(b"Versao original"),
+                86400u64,
+                options(&["Sim", "Nao"]),
+            )
+            .run();
+
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .create_amendment(
+                0u64,
+                ManagedBuffer::new_from_bytes(b"Orcamento Reduzido"),
+                ManagedBuffer::new_from_bytes(b"Versao com corte de 10%"),
+            )
+            .returns(ExpectValue(1u64))
+            .run();
+
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .create_amendment(
+                1u64,
+                ManagedBuffer::new_from_bytes(b"Emenda da Emenda"),
+                ManagedBuffer::new_from_bytes(b"Nao permitido"),
+            )
+            .with_result(ExpectMessage("Cannot amend an amendment"))
+            .run();
+
+        // Original: 1 voto "Sim"; emenda: 2 votos "Sim"
+        world
+            .tx()
+            .from(OTHER_USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .vote(0u64, ballot(&[0]))
+            .run();
+
+        for voter in [VOTER1_ADDRESS, VOTER2_ADDRESS] {
+            world
+                .tx()
+                .from(voter)
+                .to(CONTRACT_ADDRESS)
+                .typed(ChainBallotXProxy)
+                .vote(1u64, ballot(&[0]))
+                .run();
+        }
+
+        let amendments: Vec<_> = world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_proposal_amendments(0u64)
+            .returns(ReturnsResult)
+            .run()
+            .into_iter()
+            .collect();
+
+        assert_eq!(amendments, vec![1u64]);
+
+        let amendment = world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_proposal(1u64)
+            .returns(ReturnsResult)
+            .run();
+
+        assert_eq!(amendment.parent_id, Some(0u64));
+        assert_eq!(amendment.deadline, 86400u64);
+        assert_eq!(amendment.options.len(), 2);
+
+        let outcome = world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_proposal_outcome(0u64)
+            .returns(ReturnsResult)
+            .run();
+
+        assert!(outcome.passed);
+        assert_eq!(outcome.winning_option, 0usize);
+        assert_eq!(outcome.adopted_amendment, Some(1u64));
+
+        // Cancelar a original derruba as emendas abertas
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .cancel_proposal(0u64)
+            .run();
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_proposal_status(1u64)
+            .returns(ExpectValue(ProposalStatus::Cancelled))
+            .run();
+    }
+
+    #[test]
+    fn test_amendment_finalized_before_parent() {
+        let mut world = world();
+
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .put_account(OWNER_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(USER_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(VOTER1_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(VOTER2_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(OTHER_USER_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .new_address(OWNER_ADDRESS, 1, CONTRACT_ADDRESS)
+            );
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .init()
+            .code(FilePath(WASM_PATH))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .create_proposal(
+                ManagedBuffer::new_from_bytes(b"Orcamento Anual"),
+                ManagedBuffer::new_from_bytes(b"Versao original"),
+                86400u64,
+                options(&["Sim", "Nao"]),
+            )
+            .run();
+
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .create_amendment(
+                0u64,
+                ManagedBuffer::new_from_bytes(b"Orcamento Reduzido"),
+                ManagedBuffer::new_from_bytes(b"Versao com corte de 10%"),
+            )
+            .returns(ExpectValue(1u64))
+            .run();
+
+        world
+            .tx()
+            .from(OTHER_USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .vote(0u64, ballot(&[0]))
+            .run();
+
+        for voter in [VOTER1_ADDRESS, VOTER2_ADDRESS] {
+            world
+                .tx()
+                .from(voter)
+                .to(CONTRACT_ADDRESS)
+                .typed(ChainBallotXProxy)
+                .vote(1u64, ballot(&[0]))
+                .run();
+        }
+
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .block_timestamp(86401u64)
+            );
+
+        // Finalizar a emenda antes não muda o resultado da original
+        for proposal_id in [1u64, 0u64] {
+            world
+                .tx()
+                .from(OTHER_USER_ADDRESS)
+                .to(CONTRACT_ADDRESS)
+                .typed(ChainBallotXProxy)
+                .finalize_proposal(proposal_id)
+                .run();
+        }
+
+        let outcome = world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_proposal_outcome(0u64)
+            .returns(ReturnsResult)
+            .run();
+
+        assert!(outcome.passed);
+        assert_eq!(outcome.adopted_amendment, Some(1u64));
+    }
+
+    #[test]
+    fn test_deadline_extensions() {
+        let mut world = world();
+
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .put_account(OWNER_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(USER_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(VOTER1_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(VOTER2_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(OTHER_USER_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .new_address(OWNER_ADDRESS, 1, CONTRACT_ADDRESS)
+            );
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .init()
+            .code(FilePath(WASM_PATH))
+            .run();
+
+        // Janela de 10 minutos, prorrogação de 1 hora, no máximo uma vez
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .set_anti_sniping(600u64, 3600u64, 1usize)
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .create_proposal(
+                ManagedBuffer::new_from_bytes(b"Votacao Disputada"),
+                ManagedBuffer::new_from_bytes(b"Teste de anti-sniping"),
+                3600u64,
+                options(&["Sim", "Nao"]),
+            )
+            .run();
+
+        world
+            .tx()
+            .from(VOTER1_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .vote(0u64, ballot(&[0]))
+            .run();
+
+        // Empate não muda a liderança
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .block_timestamp(3300u64)
+            );
+
+        world
+            .tx()
+            .from(VOTER2_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .vote(0u64, ballot(&[1]))
+            .run();
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_proposal_deadline(0u64)
+            .returns(ExpectValue(3600u64))
+            .run();
+
+        // Virada na janela final prorroga o prazo
+        world
+            .tx()
+            .from(OTHER_USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .vote(0u64, ballot(&[1]))
+            .run();
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_proposal_deadline(0u64)
+            .returns(ExpectValue(7200u64))
+            .run();
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_proposal_extension_count(0u64)
+            .returns(ExpectValue(1usize))
+            .run();
+
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .extend_deadline(0u64, 3600u64, ManagedBuffer::new_from_bytes(b"Falha na rede"))
+            .with_result(ExpectMessage("Only owner can call this function"))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .extend_deadline(0u64, 3600u64, ManagedBuffer::new())
+            .with_result(ExpectMessage("Reason cannot be empty"))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .extend_deadline(0u64, 3600u64, ManagedBuffer::new_from_bytes(b"Falha na rede"))
+            .run();
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_proposal_deadline(0u64)
+            .returns(ExpectValue(10800u64))
+            .run();
+    }
+
+    #[test]
+    fn test_finalize_proposal() {
+        let mut world = world();
+
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .put_account(OWNER_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(USER_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(VOTER1_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .new_address(OWNER_ADDRESS, 1, CONTRACT_ADDRESS)
+            );
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .init()
+            .code(FilePath(WASM_PATH))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .set_finalization_bounty(1000u64)
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .fund_finalization_pool()
+            .egld(1500u64)
+            .run();
+
+        for title in ["Proposta Aprovada", "Proposta Sem Votos"] {
+            world
+                .tx()
+                .from(OWNER_ADDRESS)
+                .to(CONTRACT_ADDRESS)
+                .typed(ChainBallotXProxy)
+                .create_proposal(
+                    ManagedBuffer::new_from_bytes(title.as_bytes()),
+                    ManagedBuffer::new_from_bytes(b"Teste de finalizacao"),
+                    3600u64,
+                    options(&["Sim", "Nao"]),
+                )
+                .run();
+        }
+
+        world
+            .tx()
+            .from(VOTER1_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .vote(0u64, ballot(&[0]))
+            .run();
+
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .finalize_proposal(0u64)
+            .with_result(ExpectMessage("Proposal cannot be finalized"))
+            .run();
+
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .block_timestamp(3601u64)
+            );
+
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .finalize_proposal(0u64)
+            .run();
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_proposal_status(0u64)
+            .returns(ExpectValue(ProposalStatus::Passed))
+            .run();
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .is_proposal_active(0u64)
+            .returns(ExpectValue(false))
+            .run();
+
+        world
+            .check_account(USER_ADDRESS)
+            .balance("1000000000000001000");
+
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .finalize_proposal(0u64)
+            .with_result(ExpectMessage("Proposal cannot be finalized"))
+            .run();
+
+        // Proposta sem votos: finaliza sem recompensa
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .finalize_proposal(1u64)
+            .run();
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_proposal_status(1u64)
+            .returns(ExpectValue(ProposalStatus::Rejected))
+            .run();
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_finalization_pool()
+            .returns(ExpectValue(500u64))
+            .run();
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_active_proposal_count()
+            .returns(ExpectValue(0usize))
+            .run();
+
+        // Quem finaliza a própria proposta não recebe recompensa, mesmo com votos
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .fund_finalization_pool()
+            .egld(1000u64)
+            .run();
+
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .create_proposal(
+                ManagedBuffer::new_from_bytes(b"Proposta Propria"),
+                ManagedBuffer::new_from_bytes(b"Finalizada pelo criador"),
+                3600u64,
+                options(&["Sim", "Nao"]),
+            )
+            .run();
+
+        world
+            .tx()
+            .from(VOTER1_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .vote(2u64, ballot(&[0]))
+            .run();
+
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .block_timestamp(7300u64)
+            );
+
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .finalize_proposal(2u64)
+            .run();
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_finalization_pool()
+            .returns(ExpectValue(1500u64))
+            .run();
+    }
+
+    #[test]
+    fn test_ranked_choice_election() {
+        let mut world = world();
+
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .put_account(OWNER_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(USER_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(VOTER_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(VOTER1_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(VOTER2_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .new_address(OWNER_ADDRESS, 1, CONTRACT_ADDRESS)
+            );
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .init()
+            .code(FilePath(WASM_PATH))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .create_draft(
+                ManagedBuffer::new_from_bytes(b"Eleicao do Conselho"),
+                ManagedBuffer::new_from_bytes(b"Voto ranqueado"),
+                3600u64,
+                options(&["Ana", "Bruno", "Carla"]),
+            )
+            .returns(ExpectValue(0u64))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .set_ballot_type(0u64, BallotType::RankedChoice)
+            .run();
+
+        // Cédula ranqueada sem opções não pode ser publicada
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .create_draft(
+                ManagedBuffer::new_from_bytes(b"Sem Opcoes"),
+                ManagedBuffer::new_from_bytes(b"Voto ranqueado"),
+                3600u64,
+                no_options(),
+            )
+            .returns(ExpectValue(1u64))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .set_ballot_type(1u64, BallotType::RankedChoice)
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .publish_proposal(1u64)
+            .with_result(ExpectMessage("Ballot type requires options"))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .publish_proposal(0u64)
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .set_ballot_type(0u64, BallotType::SingleChoice)
+            .with_result(ExpectMessage("Proposal is not a draft"))
+            .run();
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_ballot_type(0u64)
+            .returns(ExpectValue(BallotType::RankedChoice))
+            .run();
+
+        // Cédulas inválidas
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .vote(0u64, no_ballot())
+            .with_result(ExpectMessage("Invalid ballot"))
+            .run();
+
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .vote(0u64, ballot(&[0, 0]))
+            .with_result(ExpectMessage("Duplicate option in ballot"))
+            .run();
+
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .vote(0u64, ballot(&[1, 3]))
+            .with_result(ExpectMessage("Invalid option"))
+            .run();
+
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .create_amendment(
+                0u64,
+                ManagedBuffer::new_from_bytes(b"Emenda"),
+                ManagedBuffer::new_from_bytes(b"Nao permitida"),
+            )
+            .with_result(ExpectMessage("Amendments require single-choice ballots"))
+            .run();
+
+        // Primeira rodada: Ana 2, Bruno 1, Carla 2. Bruno é eliminado e
+        // a cédula transferida dá a maioria a Carla na segunda rodada.
+        let ballots: [(TestAddress, &[usize]); 5] = [
+            (OWNER_ADDRESS, &[0]),
+            (USER_ADDRESS, &[0, 1]),
+            (VOTER_ADDRESS, &[1, 2]),
+            (VOTER1_ADDRESS, &[2]),
+            (VOTER2_ADDRESS, &[2, 0, 1]),
+        ];
+        for (voter, choices) in ballots {
+            world
+                .tx()
+                .from(voter)
+                .to(CONTRACT_ADDRESS)
+                .typed(ChainBallotXProxy)
+                .vote(0u64, ballot(choices))
+                .run();
+        }
+
+        // Antes da apuração o resultado parcial considera só as primeiras preferências
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_option_vote_count(0u64, 2usize)
+            .returns(ExpectValue(2u64))
+            .run();
+
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .block_timestamp(3601u64)
+            );
+
+        world
+            .tx()
+            .from(VOTER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .finalize_proposal(0u64)
+            .run();
+
+        let rounds: Vec<Vec<u64>> = world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_ranked_rounds(0u64)
+            .returns(ReturnsResult)
+            .run()
+            .into_iter()
+            .map(|round| round.iter().collect())
+            .collect();
+
+        assert_eq!(rounds, vec![vec![2, 1, 2], vec![2, 0, 3]]);
+
+        let outcome = world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_proposal_outcome(0u64)
+            .returns(ReturnsResult)
+            .run();
+
+        assert_eq!(outcome.winning_option, 2);
+        assert_eq!(outcome.winning_votes, 3);
+        assert_eq!(outcome.total_votes, 5);
+        assert!(outcome.passed);
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_proposal_status(0u64)
+            .returns(ExpectValue(ProposalStatus::Passed))
+            .run();
+    }
+
+    #[test]
+    fn test_approval_and_score_ballots() {
+        let mut world = world();
+
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .put_account(OWNER_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(USER_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(VOTER_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .new_address(OWNER_ADDRESS, 1, CONTRACT_ADDRESS)
+            );
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .init()
+            .code(FilePath(WASM_PATH))
+            .run();
+
+        // Proposta 0: aprovação
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .create_draft(
+                ManagedBuffer::new_from_bytes(b"Sabores do Evento"),
+                ManagedBuffer::new_from_bytes(b"Escolha todos que quiser"),
+                3600u64,
+                options(&["Chocolate", "Morango", "Baunilha"]),
+            )
+            .returns(ExpectValue(0u64))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .set_ballot_type(0u64, BallotType::Approval)
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .publish_proposal(0u64)
+            .run();
+
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .vote(0u64, ballot(&[0]))
+            .with_result(ExpectMessage("Invalid ballot"))
+            .run();
+
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .vote(0u64, ballot(&[0b1000]))
+            .with_result(ExpectMessage("Invalid option"))
+            .run();
+
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .vote(0u64, ballot(&[0b001, 0b010]))
+            .with_result(ExpectMessage("Invalid ballot"))
+            .run();
+
+        let approvals: [(TestAddress, usize); 3] = [
+            (OWNER_ADDRESS, 0b011),
+            (USER_ADDRESS, 0b110),
+            (VOTER_ADDRESS, 0b010),
+        ];
+        for (voter, approved) in approvals {
+            world
+                .tx()
+                .from(voter)
+                .to(CONTRACT_ADDRESS)
+                .typed(ChainBallotXProxy)
+                .vote(0u64, ballot(&[approved]))
+                .run();
+        }
+
+        for (option, expected) in [(0usize, 1u64), (1, 3), (2, 1)] {
+            world
+                .query()
+                .to(CONTRACT_ADDRESS)
+                .typed(ChainBallotXProxy)
+                .get_option_vote_count(0u64, option)
+                .returns(ExpectValue(expected))
+                .run();
+        }
+
+        // Proposta 1: notas de 0 a 5
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .create_draft(
+                ManagedBuffer::new_from_bytes(b"Avaliacao dos Palestrantes"),
+                ManagedBuffer::new_from_bytes(b"Notas de 0 a 5"),
+                3600u64,
+                options(&["Palestra A", "Palestra B"]),
+            )
+            .returns(ExpectValue(1u64))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .set_ballot_type(1u64, BallotType::Score)
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .publish_proposal(1u64)
+            .with_result(ExpectMessage("Score range not set"))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .set_score_range(1u64, 5usize, 0usize)
+            .with_result(ExpectMessage("Invalid score range"))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .set_score_range(1u64, 0usize, 5usize)
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .publish_proposal(1u64)
+            .run();
+
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .vote(1u64, ballot(&[4]))
+            .with_result(ExpectMessage("Invalid ballot"))
+            .run();
+
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .vote(1u64, ballot(&[6, 0]))
+            .with_result(ExpectMessage("Score out of range"))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .vote(1u64, ballot(&[5, 2]))
+            .run();
+
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .vote(1u64, ballot(&[3, 4]))
+            .run();
+
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .block_timestamp(3601u64)
+            );
+
+        for proposal_id in [0u64, 1] {
+            world
+                .tx()
+                .from(VOTER_ADDRESS)
+                .to(CONTRACT_ADDRESS)
+                .typed(ChainBallotXProxy)
+                .finalize_proposal(proposal_id)
+                .run();
+        }
+
+        let outcome = world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_proposal_outcome(0u64)
+            .returns(ReturnsResult)
+            .run();
+
+        assert_eq!(outcome.winning_option, 1);
+        assert_eq!(outcome.winning_votes, 3);
+
+        let outcome = world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_proposal_outcome(1u64)
+            .returns(ReturnsResult)
+            .run();
+
+        assert_eq!(outcome.winning_option, 0);
+        assert_eq!(outcome.winning_votes, 8);
+        assert_eq!(outcome.total_votes, 2);
+    }
+
+    #[test]
+    fn test_multi_winner_elections() {
+        let mut world = world();
+
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .put_account(OWNER_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(USER_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(VOTER_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(VOTER1_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(VOTER2_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(NEW_OWNER_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(OTHER_USER_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .new_address(OWNER_ADDRESS, 1, CONTRACT_ADDRESS)
+            );
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .init()
+            .code(FilePath(WASM_PATH))
+            .run();
+
+        // Proposta 0: STV com duas vagas
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .create_draft(
+                ManagedBuffer::new_from_bytes(b"Comite Gestor"),
+                ManagedBuffer::new_from_bytes(b"Duas vagas"),
+                3600u64,
+                options(&["Ana", "Bruno", "Carla", "Davi"]),
+            )
+            .returns(ExpectValue(0u64))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .set_ballot_type(0u64, BallotType::RankedChoice)
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .set_proposal_seats(0u64, 0usize)
+            .with_result(ExpectMessage("Invalid number of seats"))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .set_proposal_seats(0u64, 4usize)
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .publish_proposal(0u64)
+            .with_result(ExpectMessage("Seats must be fewer than options"))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .set_proposal_seats(0u64, 2usize)
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .publish_proposal(0u64)
+            .run();
+
+        // Proposta 1: pluralidade com duas vagas
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .create_draft(
+                ManagedBuffer::new_from_bytes(b"Conselho Fiscal"),
+                ManagedBuffer::new_from_bytes(b"Duas vagas"),
+                3600u64,
+                options(&["Eva", "Fabio", "Gina"]),
+            )
+            .returns(ExpectValue(1u64))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .set_proposal_seats(1u64, 2usize)
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .publish_proposal(1u64)
+            .run();
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_proposal_seats(1u64)
+            .returns(ExpectValue(2usize))
+            .run();
+
+        // Cota Droop: 7 / 3 + 1 = 3. Ana é eleita na primeira rodada e seu
+        // excedente (1/4 de cada cédula) segue para as próximas preferências.
+        let ballots: [(TestAddress, &[usize]); 7] = [
+            (OWNER_ADDRESS, &[0, 1]),
+            (USER_ADDRESS, &[0, 1]),
+            (VOTER_ADDRESS, &[0, 1]),
+            (VOTER1_ADDRESS, &[0, 2]),
+            (VOTER2_ADDRESS, &[2]),
+            (NEW_OWNER_ADDRESS, &[3, 2]),
+            (OTHER_USER_ADDRESS, &[1]),
+        ];
+        for (voter, choices) in ballots {
+            world
+                .tx()
+                .from(voter)
+                .to(CONTRACT_ADDRESS)
+                .typed(ChainBallotXProxy)
+                .vote(0u64, ballot(choices))
+                .run();
+        }
+
+        let plurality_votes: [(TestAddress, usize); 3] = [
+            (OWNER_ADDRESS, 0),
+            (USER_ADDRESS, 2),
+            (VOTER_ADDRESS, 2),
+        ];
+        for (voter, choice) in plurality_votes {
+            world
+                .tx()
+                .from(voter)
+                .to(CONTRACT_ADDRESS)
+                .typed(ChainBallotXProxy)
+                .vote(1u64, ballot(&[choice]))
+                .run();
+        }
+
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .block_timestamp(3601u64)
+            );
+
+        for proposal_id in [0u64, 1] {
+            world
+                .tx()
+                .from(VOTER_ADDRESS)
+                .to(CONTRACT_ADDRESS)
+                .typed(ChainBallotXProxy)
+                .finalize_proposal(proposal_id)
+                .run();
+        }
+
+        let rounds: Vec<Vec<u64>> = world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_ranked_rounds(0u64)
+            .returns(ReturnsResult)
+            .run()
+            .into_iter()
+            .map(|round| round.iter().collect())
+            .collect();
+
+        assert_eq!(
+            rounds,
+            vec![vec![4, 1, 1, 1], vec![0, 1, 1, 1], vec![0, 1, 2, 0], vec![0, 0, 2, 0]]
+        );
+
+        let elected: Vec<usize> = world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_elected_options(0u64)
+            .returns(ReturnsResult)
+            .run()
+            .into_iter()
+            .collect();
+
+        assert_eq!(elected, vec![0, 2]);
+
+        let elected: Vec<usize> = world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_elected_options(1u64)
+            .returns(ReturnsResult)
+            .run()
+            .into_iter()
+            .collect();
+
+        assert_eq!(elected, vec![2, 0]);
+    }
+
+    #[test]
+    fn test_stv_large_surplus() {
+        let mut world = world();
+
+        // 35 cédulas Ana > Carla, 6 só Bruno, 4 só Carla: cota 16, excedente de 19 cédulas
+        let voters: Vec<TestAddress> = (0..45)
+            .map(|i| TestAddress::new(Box::leak(format!("stv_voter_{:02}", i).into_boxed_str())))
+            .collect();
+
+        let mut state = SetStateStep::new()
+            .put_account(OWNER_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+            .new_address(OWNER_ADDRESS, 1, CONTRACT_ADDRESS);
+        for voter in voters.iter() {
+            state = state.put_account(*voter, Account::new().nonce(1).balance("1000000000000000000"));
+        }
+        world.set_state_step(state);
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .init()
+            .code(FilePath(WASM_PATH))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .create_draft(
+                ManagedBuffer::new_from_bytes(b"Comite Gestor"),
+                ManagedBuffer::new_from_bytes(b"Duas vagas"),
+                3600u64,
+                options(&["Ana", "Bruno", "Carla"]),
+            )
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .set_ballot_type(0u64, BallotType::RankedChoice)
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .set_proposal_seats(0u64, 2usize)
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .publish_proposal(0u64)
+            .run();
+
+        for (index, voter) in voters.iter().enumerate() {
+            let preferences: &[usize] = match index {
+                0..=34 => &[0, 2],
+                35..=40 => &[1],
+                _ => &[2],
+            };
+            world
+                .tx()
+                .from(*voter)
+                .to(CONTRACT_ADDRESS)
+                .typed(ChainBallotXProxy)
+                .vote(0u64, ballot(preferences))
+                .run();
+        }
+
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .block_timestamp(3601u64)
+            );
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .finalize_proposal(0u64)
+            .run();
+
+        // Carla recebe 19 votos transferidos (4 + 19 > 6) e fica com a segunda vaga
+        let elected: Vec<usize> = world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_elected_options(0u64)
+            .returns(ReturnsResult)
+            .run()
+            .into_iter()
+            .collect();
+
+        assert_eq!(elected, vec![0, 2]);
+    }
+
+    #[test]
+    fn test_conviction_voting() {
+        let mut world = world();
+
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .put_account(OWNER_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(USER_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(OTHER_USER_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(
+                        VOTER1_ADDRESS,
+                        Account::new().nonce(1).balance("1000000000000000000").esdt_balance("str:GOV-123456", "1000")
+                    )
+                    .put_account(
+                        VOTER2_ADDRESS,
+                        Account::new().nonce(1).balance("1000000000000000000").esdt_balance("str:GOV-123456", "1000")
+                    )
+                    .new_address(OWNER_ADDRESS, 1, CONTRACT_ADDRESS)
+            );
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .init()
+            .code(FilePath(WASM_PATH))
+            .run();
+
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .create_conviction_proposal(
+                ManagedBuffer::new_from_bytes(b"Bolsa Comunitaria"),
+                ManagedBuffer::new_from_bytes(b"Financiamento continuo"),
+                OTHER_USER_ADDRESS,
+                1000u64,
+            )
+            .with_result(ExpectMessage("Governance token not set"))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .set_governance_token(GOV_TOKEN)
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .set_conviction_config(10_000u64, 3600u64, 5000u64, 100u64)
+            .with_result(ExpectMessage("Invalid decay"))
+            .run();
+
+        // A convicção cai pela metade a cada hora
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .set_conviction_config(5000u64, 3600u64, 5000u64, 100u64)
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .fund_conviction_pool()
+            .egld(10_000u64)
+            .run();
+
+        for requested in [1000u64, 9000] {
+            world
+                .tx()
+                .from(USER_ADDRESS)
+                .to(CONTRACT_ADDRESS)
+                .typed(ChainBallotXProxy)
+                .create_conviction_proposal(
+                    ManagedBuffer::new_from_bytes(b"Bolsa Comunitaria"),
+                    ManagedBuffer::new_from_bytes(b"Financiamento continuo"),
+                    OTHER_USER_ADDRESS,
+                    requested,
+                )
+                .run();
+        }
+
+        world
+            .tx()
+            .from(VOTER1_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .vote(0u64, no_ballot())
+            .with_result(ExpectMessage("Conviction proposals use staking"))
+            .run();
+
+        world
+            .tx()
+            .from(VOTER1_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .stake_conviction(0u64)
+            .payment(TestEsdtTransfer(GOV_TOKEN, 0, 100))
+            .run();
+
+        world
+            .tx()
+            .from(VOTER2_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .stake_conviction(1u64)
+            .payment(TestEsdtTransfer(GOV_TOKEN, 0, 50))
+            .run();
+
+        // Pedido acima da fração máxima do pool nunca atinge o limiar
+        let threshold = world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_conviction_threshold(1u64)
+            .returns(ReturnsResult)
+            .run();
+
+        assert!(threshold.into_option().is_none());
+
+        world
+            .tx()
+            .from(VOTER2_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .move_conviction(1u64, 0u64, 20u64)
+            .run();
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_conviction_stake(1u64, VOTER2_ADDRESS)
+            .returns(ExpectValue(30u64))
+            .run();
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_conviction_staked(0u64)
+            .returns(ExpectValue(120u64))
+            .run();
+
+        // limiar = 0,01 * 150 / (0,5 * 0,4²) = 18
+        let threshold = world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_conviction_threshold(0u64)
+            .returns(ReturnsResult)
+            .run();
+
+        assert_eq!(threshold.into_option(), Some(BigUint::from(18u64)));
+
+        world
+            .tx()
+            .from(OTHER_USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .execute_conviction_proposal(0u64)
+            .with_result(ExpectMessage("Conviction threshold not reached"))
+            .run();
+
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .block_timestamp(3600u64)
+            );
+
+        // Após um período: 120 * (1 - 0,5) / (1 - 0,5) = 120
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_conviction(0u64)
+            .returns(ExpectValue(120u64))
+            .run();
+
+        world
+            .tx()
+            .from(OTHER_USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .execute_conviction_proposal(0u64)
+            .run();
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_proposal_status(0u64)
+            .returns(ExpectValue(ProposalStatus::Passed))
+            .run();
+
+        world
+            .check_account(OTHER_USER_ADDRESS)
+            .balance("1000000000000001000");
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_conviction_pool()
+            .returns(ExpectValue(9000u64))
+            .run();
+
+        // Stakes continuam sacáveis depois da aprovação
+        world
+            .tx()
+            .from(VOTER1_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .withdraw_conviction(0u64, 101u64)
+            .with_result(ExpectMessage("Insufficient stake"))
+            .run();
+
+        world
+            .tx()
+            .from(VOTER1_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .withdraw_conviction(0u64, 100u64)
+            .run();
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_conviction_total_staked()
+            .returns(ExpectValue(50u64))
+            .run();
+    }
+
+    #[test]
+    fn test_vote_escrow() {
+        let mut world = world();
+
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .put_account(OWNER_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(USER_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(
+                        VOTER1_ADDRESS,
+                        Account::new().nonce(1).balance("1000000000000000000").esdt_balance("str:GOV-123456", "1000")
+                    )
+                    .put_account(
+                        VOTER2_ADDRESS,
+                        Account::new().nonce(1).balance("1000000000000000000").esdt_balance("str:GOV-123456", "1000")
+                    )
+                    .put_account(
+                        OTHER_USER_ADDRESS,
+                        Account::new().nonce(1).balance("1000000000000000000").esdt_balance("str:GOV-123456", "1000")
+                    )
+                    .new_address(OWNER_ADDRESS, 1, CONTRACT_ADDRESS)
+            );
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .init()
+            .code(FilePath(WASM_PATH))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .set_governance_token(GOV_TOKEN)
+            .run();
+
+        world
+            .tx()
+            .from(VOTER1_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .lock_tokens(1000u64)
+            .payment(TestEsdtTransfer(GOV_TOKEN, 0, 1000))
+            .with_result(ExpectMessage("Vote escrow not configured"))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .set_vote_escrow_config(1000u64, 1u64)
+            .run();
+
+        world
+            .tx()
+            .from(VOTER1_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .lock_tokens(1001u64)
+            .payment(TestEsdtTransfer(GOV_TOKEN, 0, 1000))
+            .with_result(ExpectMessage("Invalid unlock time"))
+            .run();
+
+        world
+            .tx()
+            .from(VOTER1_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .lock_tokens(1000u64)
+            .payment(TestEsdtTransfer(GOV_TOKEN, 0, 1000))
+            .run();
+
+        world
+            .tx()
+            .from(VOTER2_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .lock_tokens(500u64)
+            .payment(TestEsdtTransfer(GOV_TOKEN, 0, 500))
+            .run();
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_voting_power(VOTER1_ADDRESS)
+            .returns(ExpectValue(1000u64))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .create_draft(
+                ManagedBuffer::new_from_bytes(b"Votacao Ponderada"),
+                ManagedBuffer::new_from_bytes(b"Poder de voto por lock"),
+                3600u64,
+                options(&["Sim", "Nao"]),
+            )
+            .returns(ExpectValue(0u64))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .set_voting_power(0u64, VotingPowerMode::VoteEscrow)
+            .run();
+
+        // Snapshot na publicação, em t = 100
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .block_timestamp(100u64)
+            );
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .publish_proposal(0u64)
+            .run();
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_proposal_snapshot(0u64)
+            .returns(ExpectValue(100u64))
+            .run();
+
+        // Lock criado depois do snapshot não dá poder de voto
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .block_timestamp(200u64)
+            );
+
+        world
+            .tx()
+            .from(OTHER_USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .lock_tokens(1200u64)
+            .payment(TestEsdtTransfer(GOV_TOKEN, 0, 1000))
+            .run();
+
+        for voter in [USER_ADDRESS, OTHER_USER_ADDRESS] {
+            world
+                .tx()
+                .from(voter)
+                .to(CONTRACT_ADDRESS)
+                .typed(ChainBallotXProxy)
+                .vote(0u64, ballot(&[1]))
+                .with_result(ExpectMessage("No voting power"))
+                .run();
+        }
+
+        // Poder no snapshot: 1000 * 900 / 1000 = 900 e 500 * 400 / 1000 = 200
+        world
+            .tx()
+            .from(VOTER1_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .vote(0u64, ballot(&[0]))
+            .run();
+
+        world
+            .tx()
+            .from(VOTER2_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .vote(0u64, ballot(&[1]))
+            .run();
+
+        for (option, expected) in [(0usize, 900u64), (1, 200)] {
+            world
+                .query()
+                .to(CONTRACT_ADDRESS)
+                .typed(ChainBallotXProxy)
+                .get_option_vote_count(0u64, option)
+                .returns(ExpectValue(expected))
+                .run();
+        }
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_voting_power(VOTER1_ADDRESS)
+            .returns(ExpectValue(800u64))
+            .run();
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_unlock_time(VOTER1_ADDRESS)
+            .returns(ExpectValue(1000u64))
+            .run();
+
+        world
+            .tx()
+            .from(VOTER1_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .extend_lock(900u64)
+            .with_result(ExpectMessage("Cannot shorten lock"))
+            .run();
+
+        world
+            .tx()
+            .from(VOTER1_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .withdraw_lock()
+            .with_result(ExpectMessage("Lock has not expired"))
+            .run();
+
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .block_timestamp(3701u64)
+            );
+
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .finalize_proposal(0u64)
+            .run();
+
+        let outcome = world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_proposal_outcome(0u64)
+            .returns(ReturnsResult)
+            .run();
+
+        assert_eq!(outcome.winning_option, 0);
+        assert_eq!(outcome.winning_votes, 900);
+        assert_eq!(outcome.total_votes, 1100);
+
+        world
+            .tx()
+            .from(VOTER1_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .withdraw_lock()
+            .run();
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_vote_escrow_total_locked()
+            .returns(ExpectValue(1500u64))
+            .run();
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_voting_power_at(VOTER1_ADDRESS, 100u64)
+            .returns(ExpectValue(900u64))
+            .run();
+    }
+
+    #[test]
+    fn test_nft_gated_voting() {
+        let mut world = world();
+
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .put_account(OWNER_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(USER_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(
+                        VOTER1_ADDRESS,
+                        Account::new()
+                            .nonce(1)
+                            .balance("1000000000000000000")
+                            .esdt_nft_balance("str:NFT-123456", 1u64, "1", Some(""))
+                            .esdt_nft_balance("str:NFT-123456", 2u64, "1", Some(""))
+                    )
+                    .put_account(
+                        VOTER2_ADDRESS,
+                        Account::new()
+                            .nonce(1)
+                            .balance("1000000000000000000")
+                            .esdt_balance("str:GOV-123456", "1000")
+                            .esdt_nft_balance("str:NFT-123456", 3u64, "1", Some(""))
+                            .esdt_nft_balance("str:NFT-123456", 4u64, "18446744073709551615", Some(""))
+                    )
+                    .new_address(OWNER_ADDRESS, 1, CONTRACT_ADDRESS)
+            );
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .init()
+            .code(FilePath(WASM_PATH))
+            .run();
+
+        // Proposta 0 devolve os NFTs na hora; proposta 1 retém até o fim da votação
+        for (proposal_id, return_immediately) in [(0u64, true), (1u64, false)] {
+            world
+                .tx()
+                .from(OWNER_ADDRESS)
+                .to(CONTRACT_ADDRESS)
+                .typed(ChainBallotXProxy)
+                .create_draft(
+                    ManagedBuffer::new_from_bytes(b"Votacao dos Holders"),
+                    ManagedBuffer::new_from_bytes(b"Um NFT um voto"),
+                    3600u64,
+                    options(&["Sim", "Nao"]),
+                )
+                .returns(ExpectValue(proposal_id))
+                .run();
+
+            world
+                .tx()
+                .from(OWNER_ADDRESS)
+                .to(CONTRACT_ADDRESS)
+                .typed(ChainBallotXProxy)
+                .set_nft_gate(proposal_id, NFT_COLLECTION, return_immediately)
+                .run();
+
+            world
+                .tx()
+                .from(OWNER_ADDRESS)
+                .to(CONTRACT_ADDRESS)
+                .typed(ChainBallotXProxy)
+                .publish_proposal(proposal_id)
+                .run();
+        }
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .create_proposal(
+                ManagedBuffer::new_from_bytes(b"Votacao Comum"),
+                ManagedBuffer::new_from_bytes(b"Sem NFT"),
+                3600u64,
+                options(&["Sim", "Nao"]),
+            )
+            .run();
+
+        world
+            .tx()
+            .from(VOTER2_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .vote(2u64, ballot(&[0]))
+            .payment(TestEsdtTransfer(NFT_COLLECTION, 3, 1))
+            .with_result(ExpectMessage("Payment not accepted"))
+            .run();
+
+        world
+            .tx()
+            .from(VOTER1_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .vote(0u64, ballot(&[0]))
+            .with_result(ExpectMessage("NFTs required to vote"))
+            .run();
+
+        world
+            .tx()
+            .from(VOTER2_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .vote(0u64, ballot(&[0]))
+            .payment(TestEsdtTransfer(GOV_TOKEN, 0, 10))
+            .with_result(ExpectMessage("Invalid NFT collection"))
+            .run();
+
+        // Saldos de SFT somados não podem estourar o peso do voto
+        world
+            .tx()
+            .from(VOTER2_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .vote(0u64, ballot(&[0]))
+            .esdt(TestEsdtTransfer(NFT_COLLECTION, 4, u64::MAX))
+            .esdt(TestEsdtTransfer(NFT_COLLECTION, 3, 1))
+            .with_result(ExpectMessage("Voting weight overflow"))
+            .run();
+
+        // Dois NFTs, peso dois
+        world
+            .tx()
+            .from(VOTER1_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .vote(0u64, ballot(&[0]))
+            .esdt(TestEsdtTransfer(NFT_COLLECTION, 1, 1))
+            .esdt(TestEsdtTransfer(NFT_COLLECTION, 2, 1))
+            .run();
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_option_vote_count(0u64, 0usize)
+            .returns(ExpectValue(2u64))
+            .run();
+
+        // O NFT devolvido não pode votar de novo na mesma proposta
+        world
+            .tx()
+            .from(VOTER1_ADDRESS)
+            .to(USER_ADDRESS)
+            .payment(TestEsdtTransfer(NFT_COLLECTION, 1, 1))
+            .run();
+
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .vote(0u64, ballot(&[1]))
+            .payment(TestEsdtTransfer(NFT_COLLECTION, 1, 1))
+            .with_result(ExpectMessage("NFT already voted on this proposal"))
+            .run();
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .is_nft_nonce_used(0u64, 1u64)
+            .returns(ExpectValue(true))
+            .run();
+
+        // A emenda herda a coleção exigida; o controle de nonces é por proposta
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .create_amendment(
+                0u64,
+                ManagedBuffer::new_from_bytes(b"Emenda dos Holders"),
+                ManagedBuffer::new_from_bytes(b"Mesma colecao"),
+            )
+            .returns(ExpectValue(3u64))
+            .run();
+
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .vote(3u64, ballot(&[0]))
+            .with_result(ExpectMessage("NFTs required to vote"))
+            .run();
+
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .vote(3u64, ballot(&[0]))
+            .payment(TestEsdtTransfer(NFT_COLLECTION, 1, 1))
+            .run();
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_option_vote_count(3u64, 0usize)
+            .returns(ExpectValue(1u64))
+            .run();
+
+        // Proposta 1: NFT fica retido até o fim da votação
+        world
+            .tx()
+            .from(VOTER2_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .vote(1u64, ballot(&[1]))
+            .payment(TestEsdtTransfer(NFT_COLLECTION, 3, 1))
+            .run();
+
+        world
+            .tx()
+            .from(VOTER2_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .claim_voting_nfts(1u64)
+            .with_result(ExpectMessage("Voting still in progress"))
+            .run();
+
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .block_timestamp(3601u64)
+            );
+
+        world
+            .tx()
+            .from(VOTER2_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .claim_voting_nfts(1u64)
+            .run();
+
+        let deposits: Vec<_> = world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_nft_deposits(1u64, VOTER2_ADDRESS)
+            .returns(ReturnsResult)
+            .run()
+            .into_iter()
+            .collect();
+
+        assert!(deposits.is_empty());
+
+        world
+            .tx()
+            .from(VOTER2_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .claim_voting_nfts(1u64)
+            .with_result(ExpectMessage("No NFTs to claim"))
+            .run();
+    }
+
+    #[test]
+    fn test_treasury() {
+        let mut world = world();
+
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .put_account(
+                        OWNER_ADDRESS,
+                        Account::new()
+                            .nonce(1)
+                            .balance("1000000000000000000")
+                            .esdt_balance("str:GOV-123456", "1000")
+                            .esdt_nft_balance("str:NFT-123456", 1u64, "1", Some(""))
+                    )
+                    .put_account(VOTER1_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(VOTER2_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(OTHER_USER_ADDRESS, Account::new().nonce(1).balance("0"))
+                    .new_address(OWNER_ADDRESS, 1, CONTRACT_ADDRESS)
+            );
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .init()
+            .code(FilePath(WASM_PATH))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .set_governance_action_rules(2u64, 5000u64)
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .deposit_to_treasury()
+            .with_result(ExpectMessage("Payment must be greater than zero"))
+            .run();
+
+        // A tesouraria só guarda tokens fungíveis
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .deposit_to_treasury()
+            .payment(TestEsdtTransfer(NFT_COLLECTION, 1, 1))
+            .with_result(ExpectMessage("Treasury only holds fungible tokens"))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .deposit_to_treasury()
+            .egld(5000u64)
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .deposit_to_treasury()
+            .payment(TestEsdtTransfer(GOV_TOKEN, 0, 800))
+            .run();
+
+        let egld = EgldOrEsdtTokenIdentifier::<StaticApi>::egld();
+        let gov = EgldOrEsdtTokenIdentifier::<StaticApi>::esdt(GOV_TOKEN.to_token_identifier());
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_treasury_balance(gov.clone(), 0u64)
+            .returns(ExpectValue(BigUint::from(800u64)))
+            .run();
+
+        let balances = world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_treasury_balances()
+            .returns(ReturnsResult)
+            .run();
+        assert_eq!(balances.to_vec().len(), 2);
+
+        // Proposta 0 paga em GOV; proposta 1 pede mais EGLD do que há na tesouraria
+        let spends = [(0u64, gov.clone(), 300u64), (1u64, egld.clone(), 6000u64)];
+        for (proposal_id, token_id, amount) in spends.iter() {
+            world
+                .tx()
+                .from(OWNER_ADDRESS)
+                .to(CONTRACT_ADDRESS)
+                .typed(ChainBallotXProxy)
+                .create_draft(
+                    ManagedBuffer::new_from_bytes(b"Gasto da Tesouraria"),
+                    ManagedBuffer::new_from_bytes(b"Pagamento ao fornecedor"),
+                    3600u64,
+                    options(&["Aprovar", "Rejeitar"]),
+                )
+                .returns(ExpectValue(*proposal_id))
+                .run();
+
+            world
+                .tx()
+                .from(OWNER_ADDRESS)
+                .to(CONTRACT_ADDRESS)
+                .typed(ChainBallotXProxy)
+                .set_treasury_spend(*proposal_id, OTHER_USER_ADDRESS, token_id.clone(), 0u64, *amount)
+                .run();
+
+            world
+                .tx()
+                .from(OWNER_ADDRESS)
+                .to(CONTRACT_ADDRESS)
+                .typed(ChainBallotXProxy)
+                .publish_proposal(*proposal_id)
+                .run();
+
+            world
+                .tx()
+                .from(VOTER1_ADDRESS)
+                .to(CONTRACT_ADDRESS)
+                .typed(ChainBallotXProxy)
+                .vote(*proposal_id, ballot(&[0]))
+                .run();
+
+            world
+                .tx()
+                .from(VOTER2_ADDRESS)
+                .to(CONTRACT_ADDRESS)
+                .typed(ChainBallotXProxy)
+                .vote(*proposal_id, ballot(&[0]))
+                .run();
+        }
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .create_amendment(
+                0u64,
+                ManagedBuffer::new_from_bytes(b"Emenda"),
+                ManagedBuffer::new_from_bytes(b"Outro valor"),
+            )
+            .with_result(ExpectMessage("Cannot amend a treasury spend proposal"))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .execute_treasury_spend(0u64)
+            .with_result(ExpectMessage("Treasury spend not approved"))
+            .run();
+
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .block_timestamp(3601u64)
+            );
+
+        for proposal_id in [0u64, 1u64] {
+            world
+                .tx()
+                .from(VOTER1_ADDRESS)
+                .to(CONTRACT_ADDRESS)
+                .typed(ChainBallotXProxy)
+                .finalize_proposal(proposal_id)
+                .run();
+        }
+
+        world
+            .check_account(OTHER_USER_ADDRESS)
+            .balance("0")
+            .esdt_balance("str:GOV-123456", "300");
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_treasury_balance(gov, 0u64)
+            .returns(ExpectValue(BigUint::from(500u64)))
+            .run();
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .is_treasury_spend_executed(0u64)
+            .returns(ExpectValue(true))
+            .run();
+
+        // Saldo insuficiente na finalização: o gasto fica pendente até novo depósito
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .is_treasury_spend_executed(1u64)
+            .returns(ExpectValue(false))
+            .run();
+
+        world
+            .tx()
+            .from(VOTER1_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .execute_treasury_spend(1u64)
+            .with_result(ExpectMessage("Insufficient treasury balance"))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .deposit_to_treasury()
+            .egld(1000u64)
+            .run();
+
+        world
+            .tx()
+            .from(VOTER1_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .execute_treasury_spend(1u64)
+            .run();
+
+        world
+            .check_account(OTHER_USER_ADDRESS)
+            .balance("6000");
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_treasury_balance(egld, 0u64)
+            .returns(ExpectValue(BigUint::from(0u64)))
+            .run();
+
+        world
+            .tx()
+            .from(VOTER1_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .execute_treasury_spend(1u64)
+            .with_result(ExpectMessage("Treasury spend not approved"))
+            .run();
+    }
+
+    #[test]
+    fn test_governance_action_rules() {
+        let mut world = world();
+
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .put_account(OWNER_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(USER_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(OTHER_USER_ADDRESS, Account::new().nonce(1).balance("0"))
+                    .new_address(OWNER_ADDRESS, 1, CONTRACT_ADDRESS)
+            );
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .init()
+            .code(FilePath(WASM_PATH))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .deposit_to_treasury()
+            .egld(5000u64)
+            .run();
+
+        // Qualquer endereço pode propor um gasto, mas não sozinho
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .create_draft(
+                ManagedBuffer::new_from_bytes(b"Gasto da Tesouraria"),
+                ManagedBuffer::new_from_bytes(b"Transferir tudo"),
+                3600u64,
+                options(&["Aprovar", "Rejeitar"]),
+            )
+            .returns(ExpectValue(0u64))
+            .run();
+
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .set_treasury_spend(0u64, OTHER_USER_ADDRESS, EgldOrEsdtTokenIdentifier::<StaticApi>::egld(), 0u64, 5000u64)
+            .run();
+
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .publish_proposal(0u64)
+            .with_result(ExpectMessage("Governance action rules not set"))
+            .run();
+
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .set_governance_action_rules(2u64, 5000u64)
+            .with_result(ExpectMessage("Only owner can call this function"))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .set_governance_action_rules(0u64, 5000u64)
+            .with_result(ExpectMessage("Invalid quorum"))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .set_governance_action_rules(2u64, 5000u64)
+            .run();
+
+        // Uma categoria permissiva não reduz os mínimos de governança
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .add_category(ManagedBuffer::new_from_bytes(b"Rapida"), 0u64, 1u64, 3600u64)
+            .returns(ExpectValue(1usize))
+            .run();
+
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .set_proposal_category(0u64, 1usize)
+            .run();
+
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .publish_proposal(0u64)
+            .run();
+
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .vote(0u64, ballot(&[0]))
+            .run();
+
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .block_timestamp(3601u64)
+            );
+
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .finalize_proposal(0u64)
+            .run();
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_proposal_status(0u64)
+            .returns(ExpectValue(ProposalStatus::Rejected))
+            .run();
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .is_treasury_spend_executed(0u64)
+            .returns(ExpectValue(false))
+            .run();
+
+        world
+            .check_account(OTHER_USER_ADDRESS)
+            .balance("0");
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_treasury_balance(EgldOrEsdtTokenIdentifier::<StaticApi>::egld(), 0u64)
+            .returns(ExpectValue(BigUint::from(5000u64)))
+            .run();
+    }
+
+    #[test]
+    fn test_vesting_streams() {
+        let mut world = world();
+
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .put_account(
+                        OWNER_ADDRESS,
+                        Account::new()
+                            .nonce(1)
+                            .balance("1000000000000000000")
+                            .esdt_balance("str:GOV-123456", "1000")
+                    )
+                    .put_account(VOTER1_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(VOTER2_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(OTHER_USER_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .new_address(OWNER_ADDRESS, 1, CONTRACT_ADDRESS)
+            );
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .init()
+            .code(FilePath(WASM_PATH))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .set_governance_action_rules(2u64, 5000u64)
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .deposit_to_treasury()
+            .payment(TestEsdtTransfer(GOV_TOKEN, 0, 1000))
+            .run();
+
+        let gov = EgldOrEsdtTokenIdentifier::<StaticApi>::esdt(GOV_TOKEN.to_token_identifier());
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .create_draft(
+                ManagedBuffer::new_from_bytes(b"Bolsa de Desenvolvimento"),
+                ManagedBuffer::new_from_bytes(b"Pagamento gradual"),
+                3600u64,
+                options(&["Aprovar", "Rejeitar"]),
+            )
+            .returns(ExpectValue(0u64))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .set_spend_vesting(0u64, 4000u64, 5000u64, 14000u64)
+            .with_result(ExpectMessage("Treasury spend not set"))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .set_treasury_spend(0u64, OTHER_USER_ADDRESS, gov.clone(), 0u64, 1000u64)
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .set_spend_vesting(0u64, 4000u64, 3000u64, 14000u64)
+            .with_result(ExpectMessage("Invalid vesting schedule"))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .set_spend_vesting(0u64, 4000u64, 5000u64, 14000u64)
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .publish_proposal(0u64)
+            .run();
+
+        world
+            .tx()
+            .from(VOTER1_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .vote(0u64, ballot(&[0]))
+            .run();
+
+        world
+            .tx()
+            .from(VOTER2_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .vote(0u64, ballot(&[0]))
+            .run();
+
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .block_timestamp(3601u64)
+            );
+
+        world
+            .tx()
+            .from(VOTER1_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .finalize_proposal(0u64)
+            .run();
+
+        // Os fundos ficam reservados para o stream, não são transferidos
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_stream_count()
+            .returns(ExpectValue(1usize))
+            .run();
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_treasury_balance(gov.clone(), 0u64)
+            .returns(ExpectValue(BigUint::from(0u64)))
+            .run();
+
+        let streams = world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_streams_by_recipient(OTHER_USER_ADDRESS)
+            .returns(ReturnsResult)
+            .run();
+        assert_eq!(streams.to_vec(), vec![1usize]);
+
+        // Antes do cliff nada é liberado
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .block_timestamp(4500u64)
+            );
+
+        world
+            .tx()
+            .from(OTHER_USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .claim_stream(1usize)
+            .with_result(ExpectMessage("Nothing to claim"))
+            .run();
+
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .block_timestamp(9000u64)
+            );
+
+        world
+            .tx()
+            .from(VOTER1_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .claim_stream(1usize)
+            .with_result(ExpectMessage("Only the recipient can claim"))
+            .run();
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_stream_vested(1usize)
+            .returns(ExpectValue(BigUint::from(500u64)))
+            .run();
+
+        world
+            .tx()
+            .from(OTHER_USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .claim_stream(1usize)
+            .run();
+
+        world
+            .check_account(OTHER_USER_ADDRESS)
+            .esdt_balance("str:GOV-123456", "500");
+
+        // Cancelamento por uma nova proposta de governança
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .create_draft(
+                ManagedBuffer::new_from_bytes(b"Cancelar Bolsa"),
+                ManagedBuffer::new_from_bytes(b"Entregas atrasadas"),
+                3600u64,
+                options(&["Cancelar", "Manter"]),
+            )
+            .returns(ExpectValue(1u64))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .set_stream_cancellation(1u64, 2usize)
+            .with_result(ExpectMessage("Stream does not exist"))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .set_stream_cancellation(1u64, 1usize)
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .publish_proposal(1u64)
+            .run();
+
+        world
+            .tx()
+            .from(VOTER1_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .vote(1u64, ballot(&[0]))
+            .run();
+
+        world
+            .tx()
+            .from(VOTER2_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .vote(1u64, ballot(&[0]))
+            .run();
+
+        world
+            .tx()
+            .from(VOTER1_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .cancel_stream(1u64)
+            .with_result(ExpectMessage("Stream cancellation not approved"))
+            .run();
+
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .block_timestamp(12601u64)
+            );
+
+        world
+            .tx()
+            .from(VOTER1_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .finalize_proposal(1u64)
+            .run();
+
+        world
+            .tx()
+            .from(VOTER1_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .cancel_stream(1u64)
+            .run();
+
+        // 860 já liberados no cancelamento; os 140 restantes voltam à tesouraria
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_treasury_balance(gov, 0u64)
+            .returns(ExpectValue(BigUint::from(140u64)))
+            .run();
+
+        world
+            .tx()
+            .from(VOTER1_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .cancel_stream(1u64)
+            .with_result(ExpectMessage("Stream already cancelled"))
+            .run();
+
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .block_timestamp(20000u64)
+            );
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_stream_claimable(1usize)
+            .returns(ExpectValue(BigUint::from(360u64)))
+            .run();
+
+        world
+            .tx()
+            .from(OTHER_USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .claim_stream(1usize)
+            .run();
+
+        world
+            .check_account(OTHER_USER_ADDRESS)
+            .esdt_balance("str:GOV-123456", "860");
+
+        world
+            .tx()
+            .from(OTHER_USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .claim_stream(1usize)
+            .with_result(ExpectMessage("Nothing to claim"))
+            .run();
+    }
+
+    #[test]
+    fn test_voting_rewards() {
+        let mut world = world();
+
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .put_account(
+                        OWNER_ADDRESS,
+                        Account::new()
+                            .nonce(1)
+                            .balance("1000000000000000000")
+                            .esdt_balance("str:GOV-123456", "1000")
+                    )
+                    .put_account(USER_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(VOTER1_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(VOTER2_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(OTHER_USER_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .new_address(OWNER_ADDRESS, 1, CONTRACT_ADDRESS)
+            );
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .init()
+            .code(FilePath(WASM_PATH))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .create_proposal(
+                ManagedBuffer::new_from_bytes(b"Proposta com Recompensa"),
+                ManagedBuffer::new_from_bytes(b"Incentivo a participacao"),
+                3600u64,
+                options(&["Sim", "Nao"]),
+            )
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .fund_voting_reward(0u64)
+            .egld(1000u64)
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .fund_voting_reward(0u64)
+            .payment(TestEsdtTransfer(GOV_TOKEN, 0, 100))
+            .with_result(ExpectMessage("Reward token mismatch"))
+            .run();
+
+        for voter in [USER_ADDRESS, VOTER1_ADDRESS, VOTER2_ADDRESS] {
+            world
+                .tx()
+                .from(voter)
+                .to(CONTRACT_ADDRESS)
+                .typed(ChainBallotXProxy)
+                .vote(0u64, ballot(&[0]))
+                .run();
+        }
+
+        world
+            .tx()
+            .from(VOTER1_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .claim_voting_reward(0u64)
+            .with_result(ExpectMessage("Proposal not finalized"))
+            .run();
+
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .block_timestamp(3601u64)
+            );
+
+        world
+            .tx()
+            .from(OTHER_USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .finalize_proposal(0u64)
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .fund_voting_reward(0u64)
+            .egld(1000u64)
+            .with_result(ExpectMessage("Proposal cannot receive rewards"))
+            .run();
+
+        // Três eleitores com o mesmo peso: 1000 / 3 = 333 cada, arredondado para baixo
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_voting_reward(0u64, VOTER1_ADDRESS)
+            .returns(ExpectValue(BigUint::from(333u64)))
+            .run();
+
+        // Resgates seguem a pausa da tesouraria
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .pause_operation(PauseScope::Treasury, OptionalValue::<u64>::None)
+            .run();
+
+        world
+            .tx()
+            .from(VOTER1_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .claim_voting_reward(0u64)
+            .with_result(ExpectMessage("Operation is paused"))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .unpause_operation(PauseScope::Treasury)
+            .run();
+
+        for voter in [VOTER1_ADDRESS, VOTER2_ADDRESS] {
+            world
+                .tx()
+                .from(voter)
+                .to(CONTRACT_ADDRESS)
+                .typed(ChainBallotXProxy)
+                .claim_voting_reward(0u64)
+                .run();
+
+            world
+                .check_account(voter)
+                .balance("1000000000000000333");
+        }
+
+        world
+            .tx()
+            .from(VOTER1_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .claim_voting_reward(0u64)
+            .with_result(ExpectMessage("Reward already claimed"))
+            .run();
+
+        world
+            .tx()
+            .from(OTHER_USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .claim_voting_reward(0u64)
+            .with_result(ExpectMessage("Nothing to claim"))
+            .run();
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .has_claimed_voting_reward(0u64, VOTER1_ADDRESS)
+            .returns(ExpectValue(true))
+            .run();
+
+        world
+            .tx()
+            .from(OTHER_USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .sweep_voting_reward(0u64)
+            .with_result(ExpectMessage("Reward claim period not ended"))
+            .run();
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_voting_reward_deadline(0u64)
+            .returns(ExpectValue(2_595_600u64))
+            .run();
+
+        // Após o prazo, a parte não resgatada vai para a tesouraria
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .block_timestamp(2_595_601u64)
+            );
+
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .claim_voting_reward(0u64)
+            .with_result(ExpectMessage("Reward claim period ended"))
+            .run();
+
+        world
+            .tx()
+            .from(OTHER_USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .sweep_voting_reward(0u64)
+            .run();
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_treasury_balance(EgldOrEsdtTokenIdentifier::<StaticApi>::egld(), 0u64)
+            .returns(ExpectValue(BigUint::from(334u64)))
+            .run();
+
+        world
+            .tx()
+            .from(OTHER_USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .sweep_voting_reward(0u64)
+            .with_result(ExpectMessage("Nothing to sweep"))
+            .run();
+
+        // Proposta cancelada: cada financiador recupera o próprio depósito
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .create_proposal(
+                ManagedBuffer::new_from_bytes(b"Proposta Cancelada"),
+                ManagedBuffer::new_from_bytes(b"Nunca chega ao fim"),
+                3600u64,
+                options(&["Sim", "Nao"]),
+            )
+            .returns(ExpectValue(1u64))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .fund_voting_reward(1u64)
+            .egld(600u64)
+            .run();
+
+        world
+            .tx()
+            .from(OTHER_USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .fund_voting_reward(1u64)
+            .egld(400u64)
+            .run();
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_voting_reward_contribution(1u64, OTHER_USER_ADDRESS)
+            .returns(ExpectValue(BigUint::from(400u64)))
+            .run();
+
+        world
+            .tx()
+            .from(OTHER_USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .refund_voting_reward(1u64)
+            .with_result(ExpectMessage("Voting reward not refundable"))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .cancel_proposal(1u64)
+            .run();
+
+        world
+            .tx()
+            .from(OTHER_USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .sweep_voting_reward(1u64)
+            .with_result(ExpectMessage("Reward claim period not ended"))
+            .run();
+
+        world
+            .tx()
+            .from(OTHER_USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .refund_voting_reward(1u64)
+            .run();
+
+        world
+            .check_account(OTHER_USER_ADDRESS)
+            .balance("1000000000000000000");
+
+        world
+            .tx()
+            .from(OTHER_USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .refund_voting_reward(1u64)
+            .with_result(ExpectMessage("Nothing to refund"))
+            .run();
+
+        world
+            .tx()
+            .from(VOTER1_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .refund_voting_reward(1u64)
+            .with_result(ExpectMessage("Nothing to refund"))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .refund_voting_reward(1u64)
+            .run();
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_voting_reward_distributed(1u64)
+            .returns(ExpectValue(BigUint::from(1000u64)))
+            .run();
+
+        // Nada do pool cancelado vai para a tesouraria
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_treasury_balance(EgldOrEsdtTokenIdentifier::<StaticApi>::egld(), 0u64)
+            .returns(ExpectValue(BigUint::from(334u64)))
+            .run();
+
+        // Finalizada sem eleitores: o pool volta aos financiadores, não à tesouraria
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .create_proposal(
+                ManagedBuffer::new_from_bytes(b"Proposta Sem Eleitores"),
+                ManagedBuffer::new_from_bytes(b"Ninguem vota"),
+                3600u64,
+                options(&["Sim", "Nao"]),
+            )
+            .returns(ExpectValue(2u64))
+            .run();
+
+        world
+            .tx()
+            .from(OTHER_USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .fund_voting_reward(2u64)
+            .egld(500u64)
+            .run();
+
+        world
+            .tx()
+            .from(OTHER_USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .refund_voting_reward(2u64)
+            .with_result(ExpectMessage("Voting reward not refundable"))
+            .run();
+
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .block_timestamp(2_599_202u64)
+            );
+
+        world
+            .tx()
+            .from(OTHER_USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .finalize_proposal(2u64)
+            .run();
+
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .block_timestamp(5_191_203u64)
+            );
+
+        world
+            .tx()
+            .from(OTHER_USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .sweep_voting_reward(2u64)
+            .with_result(ExpectMessage("No eligible voters, funders can refund"))
+            .run();
+
+        world
+            .tx()
+            .from(OTHER_USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .refund_voting_reward(2u64)
+            .run();
+
+        world
+            .check_account(OTHER_USER_ADDRESS)
+            .balance("1000000000000000000");
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_treasury_balance(EgldOrEsdtTokenIdentifier::<StaticApi>::egld(), 0u64)
+            .returns(ExpectValue(BigUint::from(334u64)))
+            .run();
+    }
+
+    #[test]
+    fn test_creator_bounties() {
+        let mut world = world();
+
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .put_account(
+                        OWNER_ADDRESS,
+                        Account::new()
+                            .nonce(1)
+                            .balance("1000000000000000000")
+                            .esdt_balance("str:GOV-123456", "1000")
+                    )
+                    .put_account(USER_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(VOTER1_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(VOTER2_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .new_address(OWNER_ADDRESS, 1, CONTRACT_ADDRESS)
+            );
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .init()
+            .code(FilePath(WASM_PATH))
+            .run();
+
+        // Proposta 0: todos os eleitores (regra padrão); 1: só quem votou na vencedora;
+        // 2: devolvida ao criador
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .create_proposal(
+                ManagedBuffer::new_from_bytes(b"Recompensa Geral"),
+                ManagedBuffer::new_from_bytes(b"Para todos os eleitores"),
+                3600u64,
+                options(&["Sim", "Nao"]),
+            )
+            .egld(900u64)
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .set_default_bounty_rule(BountyRule::WinningVoters)
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .create_proposal(
+                ManagedBuffer::new_from_bytes(b"Recompensa aos Vencedores"),
+                ManagedBuffer::new_from_bytes(b"Para quem votou na vencedora"),
+                3600u64,
+                options(&["Sim", "Nao"]),
+            )
+            .payment(TestEsdtTransfer(GOV_TOKEN, 0, 1000))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .set_default_bounty_rule(BountyRule::ReturnToCreator)
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .create_proposal(
+                ManagedBuffer::new_from_bytes(b"Recompensa Devolvida"),
+                ManagedBuffer::new_from_bytes(b"Volta ao criador"),
+                3600u64,
+                options(&["Sim", "Nao"]),
+            )
+            .egld(500u64)
+            .run();
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_creator_bounty_rule(1u64)
+            .returns(ExpectValue(BountyRule::WinningVoters))
+            .run();
+
+        // Rascunho ranqueado não pode premiar só os vencedores
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .create_draft(
+                ManagedBuffer::new_from_bytes(b"Eleicao Ranqueada"),
+                ManagedBuffer::new_from_bytes(b"Com recompensa"),
+                3600u64,
+                options(&["A", "B", "C"]),
+            )
+            .egld(100u64)
+            .returns(ExpectValue(3u64))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .set_ballot_type(3u64, BallotType::RankedChoice)
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .set_creator_bounty_rule(3u64, BountyRule::WinningVoters)
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .publish_proposal(3u64)
+            .with_result(ExpectMessage("Winning-voter bounties require a single-choice ballot"))
+            .run();
+
+        for proposal_id in [0u64, 1u64, 2u64] {
+            for (voter, choice) in [(VOTER1_ADDRESS, 0usize), (VOTER2_ADDRESS, 0usize), (USER_ADDRESS, 1usize)] {
+                world
+                    .tx()
+                    .from(voter)
+                    .to(CONTRACT_ADDRESS)
+                    .typed(ChainBallotXProxy)
+                    .vote(proposal_id, ballot(&[choice]))
+                    .run();
+            }
+        }
+
+        world
+            .tx()
+            .from(VOTER1_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .claim_creator_bounty(0u64)
+            .with_result(ExpectMessage("Proposal not finalized"))
+            .run();
+
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .block_timestamp(3601u64)
+            );
+
+        for proposal_id in [0u64, 1u64, 2u64] {
+            world
+                .tx()
+                .from(USER_ADDRESS)
+                .to(CONTRACT_ADDRESS)
+                .typed(ChainBallotXProxy)
+                .finalize_proposal(proposal_id)
+                .run();
+        }
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_creator_bounty_share(1u64, VOTER1_ADDRESS)
+            .returns(ExpectValue(BigUint::from(500u64)))
+            .run();
+
+        for voter in [VOTER1_ADDRESS, VOTER2_ADDRESS, USER_ADDRESS] {
+            world
+                .tx()
+                .from(voter)
+                .to(CONTRACT_ADDRESS)
+                .typed(ChainBallotXProxy)
+                .claim_creator_bounty(0u64)
+                .run();
+        }
+
+        for voter in [VOTER1_ADDRESS, VOTER2_ADDRESS] {
+            world
+                .tx()
+                .from(voter)
+                .to(CONTRACT_ADDRESS)
+                .typed(ChainBallotXProxy)
+                .claim_creator_bounty(1u64)
+                .run();
+
+            world
+                .check_account(voter)
+                .balance("1000000000000000300")
+                .esdt_balance("str:GOV-123456", "500");
+        }
+
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .claim_creator_bounty(1u64)
+            .with_result(ExpectMessage("Nothing to claim"))
+            .run();
+
+        world
+            .tx()
+            .from(VOTER1_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .claim_creator_bounty(0u64)
+            .with_result(ExpectMessage("Bounty already claimed"))
+            .run();
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_creator_bounty_balance(0u64)
+            .returns(ExpectValue(BigUint::from(0u64)))
+            .run();
+
+        world
+            .tx()
+            .from(VOTER1_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .claim_creator_bounty(2u64)
+            .with_result(ExpectMessage("Nothing to claim"))
+            .run();
+
+        world
+            .tx()
+            .from(VOTER1_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .reclaim_creator_bounty(2u64)
+            .with_result(ExpectMessage("Only creator can reclaim the bounty"))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .reclaim_creator_bounty(1u64)
+            .with_result(ExpectMessage("Bounty cannot be reclaimed yet"))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .reclaim_creator_bounty(2u64)
+            .run();
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_creator_bounty_balance(2u64)
+            .returns(ExpectValue(BigUint::from(0u64)))
+            .run();
+    }
+
+    #[test]
+    fn test_prediction_market() {
+        let mut world = world();
+
+        let gov_account = || {
+            Account::new()
+                .nonce(1)
+                .balance("1000000000000000000")
+                .esdt_balance("str:GOV-123456", "1000")
+        };
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .put_account(OWNER_ADDRESS, gov_account())
+                    .put_account(USER_ADDRESS, gov_account())
+                    .put_account(VOTER1_ADDRESS, gov_account())
+                    .put_account(VOTER2_ADDRESS, gov_account())
+                    .new_address(OWNER_ADDRESS, 1, CONTRACT_ADDRESS)
+            );
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .init()
+            .code(FilePath(WASM_PATH))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .set_prediction_fee(500u64)
+            .run();
+
+        let gov = EgldOrEsdtTokenIdentifier::<StaticApi>::esdt(GOV_TOKEN.to_token_identifier());
+
+        // Proposta 0 tem votos e apostas; proposta 1 termina sem votos
+        for proposal_id in [0u64, 1u64] {
+            world
+                .tx()
+                .from(OWNER_ADDRESS)
+                .to(CONTRACT_ADDRESS)
+                .typed(ChainBallotXProxy)
+                .create_draft(
+                    ManagedBuffer::new_from_bytes(b"Enquete com Apostas"),
+                    ManagedBuffer::new_from_bytes(b"Sinalizacao nao vinculante"),
+                    3600u64,
+                    options(&["Sim", "Nao"]),
+                )
+                .returns(ExpectValue(proposal_id))
+                .run();
+
+            world
+                .tx()
+                .from(OWNER_ADDRESS)
+                .to(CONTRACT_ADDRESS)
+                .typed(ChainBallotXProxy)
+                .set_prediction_market(proposal_id, gov.clone())
+                .run();
+
+            world
+                .tx()
+                .from(OWNER_ADDRESS)
+                .to(CONTRACT_ADDRESS)
+                .typed(ChainBallotXProxy)
+                .publish_proposal(proposal_id)
+                .run();
+        }
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .create_proposal(
+                ManagedBuffer::new_from_bytes(b"Enquete Comum"),
+                ManagedBuffer::new_from_bytes(b"Sem apostas"),
+                3600u64,
+                options(&["Sim", "Nao"]),
+            )
+            .run();
+
+        world
+            .tx()
+            .from(VOTER1_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .stake_prediction(2u64, 0usize)
+            .payment(TestEsdtTransfer(GOV_TOKEN, 0, 100))
+            .with_result(ExpectMessage("Not a prediction market"))
+            .run();
+
+        world
+            .tx()
+            .from(VOTER1_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .stake_prediction(0u64, 0usize)
+            .egld(100u64)
+            .with_result(ExpectMessage("Invalid stake token"))
+            .run();
+
+        for (staker, option, amount) in [
+            (VOTER1_ADDRESS, 0usize, 100u64),
+            (VOTER2_ADDRESS, 0usize, 200u64),
+            (USER_ADDRESS, 1usize, 301u64),
+        ] {
+            world
+                .tx()
+                .from(staker)
+                .to(CONTRACT_ADDRESS)
+                .typed(ChainBallotXProxy)
+                .stake_prediction(0u64, option)
+                .payment(TestEsdtTransfer(GOV_TOKEN, 0, amount))
+                .run();
+        }
+
+        world
+            .tx()
+            .from(VOTER1_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .stake_prediction(0u64, 1usize)
+            .payment(TestEsdtTransfer(GOV_TOKEN, 0, 10))
+            .with_result(ExpectMessage("Already staked on another option"))
+            .run();
+
+        world
+            .tx()
+            .from(VOTER1_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .stake_prediction(1u64, 1usize)
+            .payment(TestEsdtTransfer(GOV_TOKEN, 0, 50))
+            .run();
+
+        let pools = world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_prediction_pools(0u64)
+            .returns(ReturnsResult)
+            .run();
+        assert_eq!(
+            pools.to_vec(),
+            vec![BigUint::from(300u64), BigUint::from(301u64)]
+        );
+
+        for (voter, choice) in [(VOTER1_ADDRESS, 0usize), (VOTER2_ADDRESS, 0usize), (USER_ADDRESS, 1usize)] {
+            world
+                .tx()
+                .from(voter)
+                .to(CONTRACT_ADDRESS)
+                .typed(ChainBallotXProxy)
+                .vote(0u64, ballot(&[choice]))
+                .run();
+        }
+
+        world
+            .tx()
+            .from(VOTER1_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .claim_prediction(0u64)
+            .with_result(ExpectMessage("Proposal not finalized"))
+            .run();
+
+        // Uma emenda poderia mudar o resultado sobre o qual se apostou
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .create_amendment(
+                0u64,
+                ManagedBuffer::new_from_bytes(b"Emenda"),
+                ManagedBuffer::new_from_bytes(b"Muda a aposta"),
+            )
+            .with_result(ExpectMessage("Cannot amend a prediction market proposal"))
+            .run();
+
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .block_timestamp(3601u64)
+            );
+
+        for proposal_id in [0u64, 1u64] {
+            world
+                .tx()
+                .from(OWNER_ADDRESS)
+                .to(CONTRACT_ADDRESS)
+                .typed(ChainBallotXProxy)
+                .finalize_proposal(proposal_id)
+                .run();
+        }
+
+        // Lado perdedor 301, taxa de 5% = 15 (arredondada para baixo), 286 para os vencedores
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_treasury_balance(gov.clone(), 0u64)
+            .returns(ExpectValue(BigUint::from(15u64)))
+            .run();
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_prediction_payout(0u64, VOTER1_ADDRESS)
+            .returns(ExpectValue(BigUint::from(195u64)))
+            .run();
+
+        for staker in [VOTER1_ADDRESS, VOTER2_ADDRESS] {
+            world
+                .tx()
+                .from(staker)
+                .to(CONTRACT_ADDRESS)
+                .typed(ChainBallotXProxy)
+                .claim_prediction(0u64)
+                .run();
+        }
+
+        world
+            .tx()
+            .from(VOTER1_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .claim_prediction(0u64)
+            .with_result(ExpectMessage("Prediction already claimed"))
+            .run();
+
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .claim_prediction(0u64)
+            .with_result(ExpectMessage("Nothing to claim"))
+            .run();
+
+        // 95 + 190 pagos; a poeira de 1 vai para a tesouraria junto com a taxa
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_treasury_balance(gov, 0u64)
+            .returns(ExpectValue(BigUint::from(16u64)))
+            .run();
+
+        // Sem votos na proposta 1: as apostas são devolvidas integralmente
+        world
+            .tx()
+            .from(VOTER1_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .claim_prediction(1u64)
+            .run();
+
+        world
+            .check_account(VOTER1_ADDRESS)
+            .esdt_balance("str:GOV-123456", "1095");
+
+        world
+            .check_account(VOTER2_ADDRESS)
+            .esdt_balance("str:GOV-123456", "1190");
+
+        world
+            .check_account(USER_ADDRESS)
+            .esdt_balance("str:GOV-123456", "699");
+
+        // Proposta 3 termina empatada; na 4 a opção 0 lidera, mas falta quórum
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .add_category(ManagedBuffer::new_from_bytes(b"Exigente"), 10u64, 0u64, 3600u64)
+            .returns(ExpectValue(1usize))
+            .run();
+
+        for proposal_id in [3u64, 4u64] {
+            world
+                .tx()
+                .from(OWNER_ADDRESS)
+                .to(CONTRACT_ADDRESS)
+                .typed(ChainBallotXProxy)
+                .create_draft(
+                    ManagedBuffer::new_from_bytes(b"Enquete Indefinida"),
+                    ManagedBuffer::new_from_bytes(b"Sem vencedor claro"),
+                    3600u64,
+                    options(&["Sim", "Nao"]),
+                )
+                .returns(ExpectValue(proposal_id))
+                .run();
+
+            if proposal_id == 4 {
+                world
+                    .tx()
+                    .from(OWNER_ADDRESS)
+                    .to(CONTRACT_ADDRESS)
+                    .typed(ChainBallotXProxy)
+                    .set_proposal_category(proposal_id, 1usize)
+                    .run();
+            }
+
+            world
+                .tx()
+                .from(OWNER_ADDRESS)
+                .to(CONTRACT_ADDRESS)
+                .typed(ChainBallotXProxy)
+                .set_prediction_market(proposal_id, gov.clone())
+                .run();
+
+            world
+                .tx()
+                .from(OWNER_ADDRESS)
+                .to(CONTRACT_ADDRESS)
+                .typed(ChainBallotXProxy)
+                .publish_proposal(proposal_id)
+                .run();
+
+            for (staker, option, amount) in [(VOTER1_ADDRESS, 0usize, 40u64), (VOTER2_ADDRESS, 1usize, 60u64)] {
+                world
+                    .tx()
+                    .from(staker)
+                    .to(CONTRACT_ADDRESS)
+                    .typed(ChainBallotXProxy)
+                    .stake_prediction(proposal_id, option)
+                    .payment(TestEsdtTransfer(GOV_TOKEN, 0, amount))
+                    .run();
+
+                world
+                    .tx()
+                    .from(staker)
+                    .to(CONTRACT_ADDRESS)
+                    .typed(ChainBallotXProxy)
+                    .vote(proposal_id, ballot(&[if proposal_id == 3 { option } else { 0 }]))
+                    .run();
+            }
+        }
+
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .block_timestamp(7202u64)
+            );
+
+        for proposal_id in [3u64, 4u64] {
+            world
+                .tx()
+                .from(OWNER_ADDRESS)
+                .to(CONTRACT_ADDRESS)
+                .typed(ChainBallotXProxy)
+                .finalize_proposal(proposal_id)
+                .run();
+
+            let winning_option = world
+                .query()
+                .to(CONTRACT_ADDRESS)
+                .typed(ChainBallotXProxy)
+                .get_prediction_winning_option(proposal_id)
+                .returns(ReturnsResult)
+                .run();
+            assert!(winning_option.into_option().is_none());
+
+            for staker in [VOTER1_ADDRESS, VOTER2_ADDRESS] {
+                world
+                    .tx()
+                    .from(staker)
+                    .to(CONTRACT_ADDRESS)
+                    .typed(ChainBallotXProxy)
+                    .claim_prediction(proposal_id)
+                    .run();
+            }
+        }
+
+        // Todas as apostas devolvidas, sem taxa para a tesouraria
+        world
+            .check_account(VOTER1_ADDRESS)
+            .esdt_balance("str:GOV-123456", "1095");
+
+        world
+            .check_account(VOTER2_ADDRESS)
+            .esdt_balance("str:GOV-123456", "1190");
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_treasury_balance(EgldOrEsdtTokenIdentifier::<StaticApi>::esdt(GOV_TOKEN.to_token_identifier()), 0u64)
+            .returns(ExpectValue(BigUint::from(16u64)))
+            .run();
+    }
+
+    #[test]
+    fn test_upgrade_and_storage_migration() {
+        let mut world = world();
+
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .put_account(OWNER_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(USER_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .new_address(OWNER_ADDRESS, 1, CONTRACT_ADDRESS)
+            );
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .init()
+            .code(FilePath(WASM_PATH))
+            .run();
+
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .create_proposal(
+                ManagedBuffer::new_from_bytes(b"Proposta Antes do Upgrade"),
+                ManagedBuffer::new_from_bytes(b"Deve sobreviver ao upgrade"),
+                3600u64,
+                no_options(),
+            )
+            .run();
+
+        // Novas implantações já nascem no layout atual
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_storage_version()
+            .returns(ExpectValue(2u32))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .migrate_storage(10u64)
+            .with_result(ExpectMessage("Storage already migrated"))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .upgrade()
+            .code(FilePath(WASM_PATH))
+            .run();
+
+        let (version, migrated, total) = world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_migration_progress()
+            .returns(ReturnsResult)
+            .run()
+            .into_tuple();
+        assert_eq!((version, migrated, total), (2u32, 1u64, 1u64));
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_proposal_title(0u64)
+            .returns(ExpectValue(ManagedBuffer::new_from_bytes(b"Proposta Antes do Upgrade")))
+            .run();
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_proposal_creator(0u64)
+            .returns(ExpectValue(USER_ADDRESS.to_address()))
+            .run();
+
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .migrate_storage(10u64)
+            .with_result(ExpectMessage("Only owner can call this function"))
+            .run();
+
+        // Contrato implantado com o layout antigo: um mapper por campo, sem índices
+        const LEGACY_CONTRACT_ADDRESS: TestSCAddress = TestSCAddress::new("legacy");
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .put_account(OTHER_USER_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(VOTER1_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(VOTER2_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(
+                        LEGACY_CONTRACT_ADDRESS,
+                        Account::new()
+                            .nonce(1)
+                            .code("file:output/chainballotx.wasm")
+                            .owner(OWNER_ADDRESS)
+                            .storage("str:owner", "address:owner")
+                            .storage("str:totalProposals", "3")
+                            .storage("str:totalVotes", "3")
+                            .storage("str:userProposalCount|address:user", "2")
+                            .storage("str:userProposalCount|address:other_user", "1")
+                            .storage("str:proposalTitle|u64:0", "str:Proposta Legada")
+                            .storage("str:proposalDescription|u64:0", "str:Criada antes do upgrade")
+                            .storage("str:proposalCreator|u64:0", "address:user")
+                            .storage("str:proposalDeadline|u64:0", "3600")
+                            .storage("str:proposalVoteCount|u64:0", "2")
+                            .storage("str:proposalActive|u64:0", "true")
+                            .storage("str:hasVoted|u64:0|address:voter1", "true")
+                            .storage("str:hasVoted|u64:0|address:voter2", "true")
+                            .storage("str:proposalTitle|u64:1", "str:Proposta Encerrada")
+                            .storage("str:proposalDescription|u64:1", "str:Desativada pelo dono")
+                            .storage("str:proposalCreator|u64:1", "address:user")
+                            .storage("str:proposalDeadline|u64:1", "7200")
+                            .storage("str:proposalTitle|u64:2", "str:Outra Proposta")
+                            .storage("str:proposalDescription|u64:2", "str:De outro criador")
+                            .storage("str:proposalCreator|u64:2", "address:other_user")
+                            .storage("str:proposalDeadline|u64:2", "10800")
+                            .storage("str:proposalVoteCount|u64:2", "1")
+                            .storage("str:proposalActive|u64:2", "true")
+                            .storage("str:hasVoted|u64:2|address:voter1", "true")
+                    )
+            );
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(LEGACY_CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .upgrade()
+            .code(FilePath(WASM_PATH))
+            .run();
+
+        let (version, migrated, total) = world
+            .query()
+            .to(LEGACY_CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_migration_progress()
+            .returns(ReturnsResult)
+            .run()
+            .into_tuple();
+        assert_eq!((version, migrated, total), (1u32, 0u64, 3u64));
+
+        // Antes da migração os campos fixos são lidos do layout antigo
+        world
+            .query()
+            .to(LEGACY_CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_proposal_title(2u64)
+            .returns(ExpectValue(ManagedBuffer::new_from_bytes(b"Outra Proposta")))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(LEGACY_CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .migrate_storage(0u64)
+            .with_result(ExpectMessage("Batch size must be greater than zero"))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(LEGACY_CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .migrate_storage(2u64)
+            .returns(ExpectValue(false))
+            .run();
+
+        let (version, migrated, total) = world
+            .query()
+            .to(LEGACY_CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_migration_progress()
+            .returns(ReturnsResult)
+            .run()
+            .into_tuple();
+        assert_eq!((version, migrated, total), (1u32, 2u64, 3u64));
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(LEGACY_CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .migrate_storage(2u64)
+            .returns(ExpectValue(true))
+            .run();
+
+        world
+            .query()
+            .to(LEGACY_CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_storage_version()
+            .returns(ExpectValue(2u32))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(LEGACY_CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .migrate_storage(2u64)
+            .with_result(ExpectMessage("Storage already migrated"))
+            .run();
+
+        world
+            .query()
+            .to(LEGACY_CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_proposal_title(0u64)
+            .returns(ExpectValue(ManagedBuffer::new_from_bytes(b"Proposta Legada")))
+            .run();
+
+        world
+            .query()
+            .to(LEGACY_CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_proposal_creator(2u64)
+            .returns(ExpectValue(OTHER_USER_ADDRESS.to_address()))
+            .run();
+
+        // Índices preenchidos a partir do layout antigo
+        let created: Vec<u64> = world
+            .query()
+            .to(LEGACY_CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_proposals_by_creator(USER_ADDRESS.to_address(), 0usize, 10usize)
+            .returns(ReturnsResult)
+            .run()
+            .into_iter()
+            .map(|proposal| proposal.id)
+            .collect();
+        assert_eq!(created, vec![0u64, 1u64]);
+
+        world
+            .query()
+            .to(LEGACY_CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_creator_proposal_count(OTHER_USER_ADDRESS.to_address())
+            .returns(ExpectValue(1usize))
+            .run();
+
+        world
+            .query()
+            .to(LEGACY_CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_category_proposal_count(0usize)
+            .returns(ExpectValue(3usize))
+            .run();
+
+        world
+            .query()
+            .to(LEGACY_CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_active_proposal_count()
+            .returns(ExpectValue(2usize))
+            .run();
+
+        world
+            .query()
+            .to(LEGACY_CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_option_vote_count(0u64, 0usize)
+            .returns(ExpectValue(2u64))
+            .run();
+
+        world
+            .query()
+            .to(LEGACY_CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_option_vote_count(2u64, 0usize)
+            .returns(ExpectValue(1u64))
+            .run();
+
+        world
+            .query()
+            .to(LEGACY_CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_proposal_snapshot(1u64)
+            .returns(ExpectValue(7200u64))
+            .run();
+
+        world
+            .query()
+            .to(LEGACY_CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_proposal_status(1u64)
+            .returns(ExpectValue(ProposalStatus::Cancelled))
+            .run();
+
+        // Propostas migradas seguem o fluxo atual: voto, finalização e limite por usuário
+        world
+            .tx()
+            .from(VOTER1_ADDRESS)
+            .to(LEGACY_CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .vote(0u64, ballot(&[0]))
+            .with_result(ExpectMessage("Already voted on this proposal"))
+            .run();
+
+        world
+            .tx()
+            .from(VOTER2_ADDRESS)
+            .to(LEGACY_CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .vote(2u64, ballot(&[0]))
+            .run();
+
+        world
+            .query()
+            .to(LEGACY_CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_option_vote_count(2u64, 0usize)
+            .returns(ExpectValue(2u64))
+            .run();
+
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .block_timestamp(3601u64)
+            );
+
+        world
+            .tx()
+            .from(OTHER_USER_ADDRESS)
+            .to(LEGACY_CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .finalize_proposal(0u64)
+            .run();
+
+        world
+            .query()
+            .to(LEGACY_CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_proposal_status(0u64)
+            .returns(ExpectValue(ProposalStatus::Passed))
+            .run();
+
+        world
+            .query()
+            .to(LEGACY_CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_active_proposal_count()
+            .returns(ExpectValue(1usize))
+            .run();
+    }
+
+    #[test]
+    fn test_governance_code_upgrade() {
+        let mut world = world();
+        // Nova versão registrada sob outro código; a proposta aprova o hash desse código
+        world.register_contract("str:chainballotx-v2", chainballotx::ContractBuilder);
+        let new_code = ManagedBuffer::<StaticApi>::new_from_bytes(b"chainballotx-v2");
+        let new_code_hash = ManagedBuffer::<StaticApi>::new_from_bytes(
+            &multiversx_chain_vm::crypto_functions::sha256(b"chainballotx-v2"),
+        );
+
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .put_account(OWNER_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(VOTER1_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(VOTER2_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .new_address(OWNER_ADDRESS, 1, CONTRACT_ADDRESS)
+            );
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .init()
+            .code(FilePath(WASM_PATH))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .set_governance_action_rules(2u64, 5000u64)
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .create_draft(
+                ManagedBuffer::new_from_bytes(b"Upgrade do Contrato"),
+                ManagedBuffer::new_from_bytes(b"Nova versao auditada"),
+                3600u64,
+                options(&["Aprovar", "Rejeitar"]),
+            )
+            .returns(ExpectValue(0u64))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .set_code_upgrade(0u64, ManagedBuffer::new_from_bytes(b"curto"), CodeMetadata::UPGRADEABLE)
+            .with_result(ExpectMessage("Invalid code hash"))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .set_code_upgrade(0u64, new_code_hash, CodeMetadata::UPGRADEABLE)
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .set_treasury_spend(0u64, VOTER1_ADDRESS, EgldOrEsdtTokenIdentifier::<StaticApi>::egld(), 0u64, 100u64)
+            .with_result(ExpectMessage("Proposal already has a governance action"))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .publish_proposal(0u64)
+            .run();
+
+        world
+            .tx()
+            .from(VOTER1_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .vote(0u64, ballot(&[0]))
+            .run();
+
+        world
+            .tx()
+            .from(VOTER2_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .vote(0u64, ballot(&[0]))
+            .run();
+
+        world
+            .tx()
+            .from(VOTER1_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .execute_code_upgrade(0u64, ManagedBuffer::new_from_bytes(b"wasm"))
+            .with_result(ExpectMessage("Code upgrade not approved"))
+            .run();
+
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .block_timestamp(3601u64)
+            );
+
+        world
+            .tx()
+            .from(VOTER1_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .finalize_proposal(0u64)
+            .run();
+
+        // Prazo obrigatório de 2 dias após o fim da votação
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_code_upgrade_eta(0u64)
+            .returns(ExpectValue(3600u64 + 172_800u64))
+            .run();
+
+        world
+            .tx()
+            .from(VOTER1_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .execute_code_upgrade(0u64, ManagedBuffer::new_from_bytes(b"wasm"))
+            .with_result(ExpectMessage("Upgrade delay not elapsed"))
+            .run();
+
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .block_timestamp(176_400u64)
+            );
+
+        // O código enviado precisa ter exatamente o hash aprovado na votação
+        world
+            .tx()
+            .from(VOTER1_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .execute_code_upgrade(0u64, ManagedBuffer::new_from_bytes(b"wasm"))
+            .with_result(ExpectMessage("Code hash mismatch"))
+            .run();
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .is_code_upgrade_executed(0u64)
+            .returns(ExpectValue(false))
+            .run();
+
+        // Enquanto o deployer for o dono, a proposta continua executável
+        world
+            .tx()
+            .from(VOTER1_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .execute_code_upgrade(0u64, new_code.clone())
+            .with_result(ExpectMessage("Contract must own itself to upgrade"))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(UserBuiltinProxy)
+            .change_owner_address(&CONTRACT_ADDRESS.to_managed_address())
+            .run();
+
+        world
+            .tx()
+            .from(VOTER1_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .execute_code_upgrade(0u64, new_code.clone())
+            .run();
+
+        world
+            .check_state_step(
+                CheckStateStep::new()
+                    .put_account(CONTRACT_ADDRESS, CheckAccount::new().code("str:chainballotx-v2"))
+            );
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .is_code_upgrade_executed(0u64)
+            .returns(ExpectValue(true))
+            .run();
+
+        world
+            .tx()
+            .from(VOTER1_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .execute_code_upgrade(0u64, new_code)
+            .with_result(ExpectMessage("Code upgrade already executed"))
+            .run();
+    }
+
+    #[test]
+    fn test_spaces() {
+        let mut world = world();
+
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .put_account(OWNER_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(USER_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(VOTER1_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(VOTER2_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(OTHER_USER_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .new_address(OWNER_ADDRESS, 1, CONTRACT_ADDRESS)
+            );
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .init()
+            .code(FilePath(WASM_PATH))
+            .run();
+
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .create_space(ManagedBuffer::new_from_bytes(b"DAO A"), USER_ADDRESS)
+            .with_result(ExpectMessage("Only owner can call this function"))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .create_space(ManagedBuffer::new_from_bytes(b"DAO A"), USER_ADDRESS)
+            .returns(ExpectValue(1usize))
+            .run();
+
+        // Apenas administradores do espaço gerenciam o registro de eleitores
+        let mut voters = MultiValueEncoded::<StaticApi, ManagedAddress<StaticApi>>::new();
+        voters.push(VOTER1_ADDRESS.to_managed_address());
+        voters.push(VOTER2_ADDRESS.to_managed_address());
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .register_space_voters(1usize, voters.clone())
+            .with_result(ExpectMessage("Only space admin can call this function"))
+            .run();
+
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .register_space_voters(1usize, voters)
+            .run();
+
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .set_space_config(1usize, 2u64, 5000u64, 7200u64)
+            .run();
+
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .remove_space_admin(1usize, USER_ADDRESS)
+            .with_result(ExpectMessage("Space must keep an admin"))
+            .run();
+
+        world
+            .tx()
+            .from(OTHER_USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .create_space_proposal(
+                1usize,
+                ManagedBuffer::new_from_bytes(b"Proposta do Espaco"),
+                ManagedBuffer::new_from_bytes(b"Apenas membros"),
+                7200u64,
+                no_options(),
+            )
+            .with_result(ExpectMessage("Only space members can create proposals"))
+            .run();
+
+        world
+            .tx()
+            .from(VOTER1_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .create_space_proposal(
+                1usize,
+                ManagedBuffer::new_from_bytes(b"Proposta do Espaco"),
+                ManagedBuffer::new_from_bytes(b"Regras do espaco"),
+                3600u64,
+                no_options(),
+            )
+            .with_result(ExpectMessage("Duration too short"))
+            .run();
+
+        world
+            .tx()
+            .from(VOTER1_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .create_space_proposal(
+                1usize,
+                ManagedBuffer::new_from_bytes(b"Proposta do Espaco"),
+                ManagedBuffer::new_from_bytes(b"Regras do espaco"),
+                7200u64,
+                options(&["Sim", "Nao"]),
+            )
+            .returns(ExpectValue(0u64))
+            .run();
+
+        // Proposta do espaço padrão intercalada: a numeração do espaço é independente
+        world
+            .tx()
+            .from(OTHER_USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .create_proposal(
+                ManagedBuffer::new_from_bytes(b"Proposta Global"),
+                ManagedBuffer::new_from_bytes(b"Espaco padrao"),
+                3600u64,
+                no_options(),
+            )
+            .run();
+
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .create_space_proposal(
+                1usize,
+                ManagedBuffer::new_from_bytes(b"Segunda Proposta"),
+                ManagedBuffer::new_from_bytes(b"Criada pelo admin"),
+                7200u64,
+                no_options(),
+            )
+            .returns(ExpectValue(1u64))
+            .run();
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_space_proposal_id(1usize, 1u64)
+            .returns(ExpectValue(2u64))
+            .run();
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_proposal_space(1u64)
+            .returns(ExpectValue(0usize))
+            .run();
+
+        // Votos só pelo espaço e apenas de eleitores registrados
+        world
+            .tx()
+            .from(VOTER1_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .vote(0u64, ballot(&[0]))
+            .with_result(ExpectMessage("Proposal belongs to a space"))
+            .run();
+
+        world
+            .tx()
+            .from(OTHER_USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .vote_in_space(1usize, 0u64, ballot(&[0]))
+            .with_result(ExpectMessage("Not a registered voter in this space"))
+            .run();
+
+        for voter in [VOTER1_ADDRESS, VOTER2_ADDRESS] {
+            world
+                .tx()
+                .from(voter)
+                .to(CONTRACT_ADDRESS)
+                .typed(ChainBallotXProxy)
+                .vote_in_space(1usize, 0u64, ballot(&[0]))
+                .run();
+        }
+
+        world
+            .tx()
+            .from(OTHER_USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .vote(1u64, no_ballot())
+            .run();
+
+        world
+            .tx()
+            .from(OTHER_USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .cancel_space_proposal(1usize, 1u64)
+            .with_result(ExpectMessage("Only creator or space admin can cancel proposal"))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .cancel_proposal(2u64)
+            .with_result(ExpectMessage("Proposal belongs to a space"))
+            .run();
+
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .cancel_space_proposal(1usize, 1u64)
+            .run();
+
+        // Contadores por espaço e globais
+        let space = world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_space(1usize)
+            .returns(ReturnsResult)
+            .run();
+
+        assert_eq!(space.admin_count, 1);
+        assert_eq!(space.voter_count, 2);
+        assert_eq!(space.total_proposals, 2);
+        assert_eq!(space.total_votes, 2);
+        assert_eq!(space.config.quorum, 2);
+
+        // Os contadores e listagens do espaço padrão ignoram as propostas de espaços
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_total_votes()
+            .returns(ExpectValue(1u64))
+            .run();
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_default_proposal_count()
+            .returns(ExpectValue(1u64))
+            .run();
+
+        // O total de ids continua global
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_total_proposals()
+            .returns(ExpectValue(3u64))
+            .run();
+
+        let proposals = world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_proposals(0u64, 10usize)
+            .returns(ReturnsResult)
+            .run();
+        let ids: Vec<u64> = proposals.into_iter().map(|proposal| proposal.id).collect();
+        assert_eq!(ids, vec![1u64]);
+
+        let proposals = world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_proposals_by_status(ProposalStatus::Active, 0usize, 10usize)
+            .returns(ReturnsResult)
+            .run();
+        let ids: Vec<u64> = proposals.into_iter().map(|proposal| proposal.id).collect();
+        assert_eq!(ids, vec![1u64]);
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_active_proposal_count()
+            .returns(ExpectValue(1usize))
+            .run();
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_creator_proposal_count(VOTER1_ADDRESS)
+            .returns(ExpectValue(0usize))
+            .run();
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_voter_vote_count(VOTER1_ADDRESS)
+            .returns(ExpectValue(0usize))
+            .run();
+
+        // Rascunhos, emendas, categorias, recompensas e prazos são exclusivos do espaço padrão
+        world
+            .tx()
+            .from(VOTER1_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .set_proposal_tags(0u64, options(&["dao"]))
+            .with_result(ExpectMessage("Proposal belongs to a space"))
+            .run();
+
+        world
+            .tx()
+            .from(VOTER1_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .create_amendment(
+                0u64,
+                ManagedBuffer::new_from_bytes(b"Emenda"),
+                ManagedBuffer::new_from_bytes(b"Fora do espaco"),
+            )
+            .with_result(ExpectMessage("Proposal belongs to a space"))
+            .run();
+
+        world
+            .tx()
+            .from(OTHER_USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .fund_voting_reward(0u64)
+            .egld(100u64)
+            .with_result(ExpectMessage("Proposal belongs to a space"))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .extend_deadline(0u64, 3600u64, ManagedBuffer::new_from_bytes(b"Mais tempo"))
+            .with_result(ExpectMessage("Proposal belongs to a space"))
+            .run();
+
+        // O quórum do espaço foi copiado para a proposta e é atingido com dois votos
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .block_timestamp(7201u64)
+            );
+
+        world
+            .tx()
+            .from(OTHER_USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .finalize_proposal(0u64)
+            .with_result(ExpectMessage("Proposal belongs to a space"))
+            .run();
+
+        world
+            .tx()
+            .from(OTHER_USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .finalize_space_proposal(1usize, 0u64)
+            .run();
+
+        let proposal = world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_space_proposal(1usize, 0u64)
+            .returns(ReturnsResult)
+            .run();
+
+        assert_eq!(proposal.id, 0u64);
+        assert_eq!(proposal.status, ProposalStatus::Passed);
+
+        // O limite por usuário vale dentro de cada espaço
+        for _ in 1..10 {
+            world
+                .tx()
+                .from(VOTER1_ADDRESS)
+                .to(CONTRACT_ADDRESS)
+                .typed(ChainBallotXProxy)
+                .create_space_proposal(
+                    1usize,
+                    ManagedBuffer::new_from_bytes(b"Mais uma"),
+                    ManagedBuffer::new_from_bytes(b"Dentro do limite"),
+                    7200u64,
+                    no_options(),
+                )
+                .run();
+        }
+
+        world
+            .tx()
+            .from(VOTER1_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .create_space_proposal(
+                1usize,
+                ManagedBuffer::new_from_bytes(b"Uma a mais"),
+                ManagedBuffer::new_from_bytes(b"Acima do limite"),
+                7200u64,
+                no_options(),
+            )
+            .with_result(ExpectMessage("Maximum proposals per user exceeded"))
+            .run();
+    }
+
+    #[test]
+    fn test_granular_pause() {
+        let mut world = world();
+
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .put_account(OWNER_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(USER_ADDRESS, Account::new().nonce(1).balance("1000000000000000000"))
+                    .put_account(
+                        VOTER_ADDRESS,
+                        Account::new().nonce(1).balance("1000000000000000000").esdt_balance("str:GOV-123456", "1000")
+                    )
+                    .put_account(OTHER_USER_ADDRESS, Account::new().nonce(1).balance("0"))
+                    .new_address(OWNER_ADDRESS, 1, CONTRACT_ADDRESS)
+                    .block_timestamp(100u64)
+            );
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .init()
+            .code(FilePath(WASM_PATH))
+            .run();
+
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .pause_operation(PauseScope::Voting, OptionalValue::Some(3600u64))
+            .with_result(ExpectMessage("Only pauser can call this function"))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .add_pauser(USER_ADDRESS)
+            .run();
+
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .pause_operation(PauseScope::Voting, OptionalValue::Some(50u64))
+            .with_result(ExpectMessage("Invalid unpause timestamp"))
+            .run();
+
+        // Votação pausada até 3600; criação e cancelamento seguem liberados
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .pause_operation(PauseScope::Voting, OptionalValue::Some(3600u64))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .create_proposal(
+                ManagedBuffer::new_from_bytes(b"Proposta Pausada"),
+                ManagedBuffer::new_from_bytes(b"Votacao suspensa"),
+                7200u64,
+                no_options(),
+            )
+            .run();
+
+        world
+            .tx()
+            .from(VOTER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .vote(0u64, no_ballot())
+            .with_result(ExpectMessage("Operation is paused"))
+            .run();
+
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .pause_operation(PauseScope::Cancellation, OptionalValue::<u64>::None)
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .cancel_proposal(0u64)
+            .with_result(ExpectMessage("Operation is paused"))
+            .run();
+
+        let state = world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_pause_state()
+            .returns(ReturnsResult)
+            .run();
+
+        assert!(!state.contract_paused);
+        assert!(!state.creation.paused);
+        assert!(state.voting.paused);
+        assert_eq!(state.voting.unpause_at, Some(3600u64));
+        assert!(state.cancellation.paused);
+        assert_eq!(state.cancellation.unpause_at, None);
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .is_contract_paused()
+            .returns(ExpectValue(false))
+            .run();
+
+        // A pausa de votação expira sozinha
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .block_timestamp(3600u64)
+            );
+
+        world
+            .tx()
+            .from(VOTER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .vote(0u64, no_ballot())
+            .run();
+
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .unpause_operation(PauseScope::Cancellation)
+            .run();
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .is_operation_paused(PauseScope::Cancellation)
+            .returns(ExpectValue(false))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .remove_pauser(USER_ADDRESS)
+            .run();
+
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .pause_operation(PauseScope::Treasury, OptionalValue::<u64>::None)
+            .with_result(ExpectMessage("Only pauser can call this function"))
+            .run();
+
+        // Com a execução pausada, atingir o limiar de convicção não libera o pagamento
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .set_governance_token(GOV_TOKEN)
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .set_conviction_config(5000u64, 3600u64, 5000u64, 100u64)
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .fund_conviction_pool()
+            .egld(10_000u64)
+            .run();
+
+        world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .create_conviction_proposal(
+                ManagedBuffer::new_from_bytes(b"Bolsa Comunitaria"),
+                ManagedBuffer::new_from_bytes(b"Financiamento continuo"),
+                OTHER_USER_ADDRESS,
+                1000u64,
+            )
+            .returns(ExpectValue(1u64))
+            .run();
+
+        world
+            .tx()
+            .from(VOTER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .stake_conviction(1u64)
+            .payment(TestEsdtTransfer(GOV_TOKEN, 0, 100))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .pause_operation(PauseScope::Execution, OptionalValue::<u64>::None)
+            .run();
+
+        world
+            .set_state_step(
+                SetStateStep::new()
+                    .block_timestamp(7200u64)
+            );
+
+        world
+            .tx()
+            .from(VOTER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .stake_conviction(1u64)
+            .payment(TestEsdtTransfer(GOV_TOKEN, 0, 10))
+            .run();
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_proposal_status(1u64)
+            .returns(ExpectValue(ProposalStatus::Active))
+            .run();
+
+        world
+            .check_account(OTHER_USER_ADDRESS)
+            .balance("0");
+
+        world
+            .tx()
+            .from(OTHER_USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .execute_conviction_proposal(1u64)
+            .with_result(ExpectMessage("Operation is paused"))
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .unpause_operation(PauseScope::Execution)
+            .run();
+
+        world
+            .tx()
+            .from(OTHER_USER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .execute_conviction_proposal(1u64)
+            .run();
+
+        world
+            .query()
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .get_proposal_status(1u64)
+            .returns(ExpectValue(ProposalStatus::Passed))
+            .run();
+
+        world
+            .check_account(OTHER_USER_ADDRESS)
+            .balance("1000");
+
+        // A pausa geral continua bloqueando todas as operações
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .pause()
+            .run();
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(CONTRACT_ADDRESS)
+            .typed(ChainBallotXProxy)
+            .cancel_proposal(0u64)
+            .with_result(ExpectMessage("Contract is paused"))
+            .run();
+    }
+
+    fn no_options() -> MultiValueEncoded<StaticApi, ManagedBuffer<StaticApi>> {
+        MultiValueEncoded::new()
+    }
+
+    fn options(names: &[&str]) -> MultiValueEncoded<StaticApi, ManagedBuffer<StaticApi>> {
+        let mut options = MultiValueEncoded::new();
+        for name in names {
+            options.push(ManagedBuffer::new_from_bytes(name.as_bytes()));
+        }
+        options
+    }
+
+    fn no_ballot() -> MultiValueEncoded<StaticApi, usize> {
+        MultiValueEncoded::new()
+    }
+
+    fn ballot(choices: &[usize]) -> MultiValueEncoded<StaticApi, usize> {
+        let mut ballot = MultiValueEncoded::new();
+        for choice in choices {
+            ballot.push(*choice);
+        }
+        ballot
+    }
+
     fn world() -> ScenarioWorld {
         let mut world = ScenarioWorld::new();
         world.register_contract("file:output/chainballotx.wasm", chainballotx::ContractBuilder);
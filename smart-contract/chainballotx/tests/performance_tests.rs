@@ -6,6 +6,8 @@ use multiversx_sc_scenario::imports::{
     FilePath,
     ExpectMessage,
     ExpectValue,
+    MultiValueEncoded,
+    StaticApi,
     TestAddress,
     TestSCAddress,
 };
@@ -74,6 +76,7 @@ mod chainballotx_performance_tests {
                 ManagedBuffer::new_from_bytes(b"Proposta de Stress Test"),
                 ManagedBuffer::new_from_bytes(b"Teste com muitos votantes"),
                 86400u64,
+                no_options(),
             )
             .run();
 
@@ -100,7 +103,7 @@ mod chainballotx_performance_tests {
                 .from(*voter_address)
                 .to(CONTRACT_ADDRESS)
                 .typed(ChainBallotXProxy)
-                .vote(0u64)
+                .vote(0u64, no_ballot())
                 .run();
             vote_times.push(vote_start.elapsed().as_millis());
 
@@ -182,6 +185,7 @@ mod chainballotx_performance_tests {
                     ManagedBuffer::new_from_bytes(title.as_bytes()),
                     ManagedBuffer::new_from_bytes(description.as_bytes()),
                     86400u64,
+                    no_options(),
                 )
                 .gas(50_000_000)
                 .run();
@@ -196,6 +200,7 @@ mod chainballotx_performance_tests {
                 ManagedBuffer::new_from_bytes(b"Proposta Extra"),
                 ManagedBuffer::new_from_bytes(b"Esta deve falhar"),
                 86400u64,
+                no_options(),
             )
             .gas(50_000_000)
             .with_result(ExpectMessage("Maximum proposals per user exceeded"))
@@ -246,6 +251,7 @@ mod chainballotx_performance_tests {
                     ManagedBuffer::new_from_bytes(title.as_bytes()),
                     ManagedBuffer::new_from_bytes(description.as_bytes()),
                     86400u64,
+                    no_options(),
                 )
                 .run();
         }
@@ -277,7 +283,7 @@ mod chainballotx_performance_tests {
                 .from(*voter_address)
                 .to(CONTRACT_ADDRESS)
                 .typed(ChainBallotXProxy)
-                .vote(proposal_id as u64)
+                .vote(proposal_id as u64, no_ballot())
                 .run();
             vote_times.push(vote_start.elapsed().as_millis());
             proposal_votes[proposal_id] += 1;
@@ -360,6 +366,7 @@ mod chainballotx_performance_tests {
                 ManagedBuffer::new_from_bytes(b"Proposta com Descricao Grande"),
                 ManagedBuffer::new_from_bytes(large_description.as_bytes()),
                 86400u64,
+                no_options(),
             )
             .run();
 
@@ -383,7 +390,7 @@ mod chainballotx_performance_tests {
                 .from(*voter_address)
                 .to(CONTRACT_ADDRESS)
                 .typed(ChainBallotXProxy)
-                .vote(0u64)
+                .vote(0u64, no_ballot())
                 .run();
         }
 
@@ -404,6 +411,14 @@ mod chainballotx_performance_tests {
             .run();
     }
 
+    fn no_options() -> MultiValueEncoded<StaticApi, ManagedBuffer<StaticApi>> {
+        MultiValueEncoded::new()
+    }
+
+    fn no_ballot() -> MultiValueEncoded<StaticApi, usize> {
+        MultiValueEncoded::new()
+    }
+
     fn world() -> ScenarioWorld {
         let mut world = ScenarioWorld::new();
         world.register_contract("file:output/chainballotx.wasm", chainballotx::ContractBuilder);
@@ -5,9 +5,10 @@
 ////////////////////////////////////////////////////
 
 // Init:                                 1
-// Endpoints:                           17
+// Upgrade:                              1
+// Endpoints:                          190
 // Async Callback (empty):               1
-// Total number of exported functions:  19
+// Total number of exported functions: 193
 
 #![no_std]
 
@@ -18,12 +19,82 @@ multiversx_sc_wasm_adapter::endpoints! {
     chainballotx
     (
         init => init
+        upgrade => upgrade
         pause => pause
         unpause => unpause
+        add_pauser => add_pauser
+        remove_pauser => remove_pauser
+        pause_operation => pause_operation
+        unpause_operation => unpause_operation
         transfer_ownership => transfer_ownership
+        add_category => add_category
+        update_category => update_category
+        set_anti_sniping => set_anti_sniping
+        extend_deadline => extend_deadline
+        set_finalization_bounty => set_finalization_bounty
+        set_governance_token => set_governance_token
+        set_conviction_config => set_conviction_config
+        set_vote_escrow_config => set_vote_escrow_config
+        set_default_bounty_rule => set_default_bounty_rule
+        set_governance_action_rules => set_governance_action_rules
+        set_prediction_fee => set_prediction_fee
+        migrate_storage => migrate_storage
         create_proposal => create_proposal
+        create_draft => create_draft
+        create_amendment => create_amendment
+        edit_proposal => edit_proposal
+        set_ballot_type => set_ballot_type
+        set_score_range => set_score_range
+        set_proposal_seats => set_proposal_seats
+        set_voting_power => set_voting_power
+        set_nft_gate => set_nft_gate
+        set_treasury_spend => set_treasury_spend
+        set_spend_vesting => set_spend_vesting
+        set_stream_cancellation => set_stream_cancellation
+        set_code_upgrade => set_code_upgrade
+        set_creator_bounty_rule => set_creator_bounty_rule
+        set_prediction_market => set_prediction_market
+        publish_proposal => publish_proposal
         vote => vote
+        set_proposal_document => set_proposal_document
+        set_proposal_category => set_proposal_category
+        set_proposal_tags => set_proposal_tags
         cancel_proposal => cancel_proposal
+        finalize_proposal => finalize_proposal
+        fund_finalization_pool => fund_finalization_pool
+        create_conviction_proposal => create_conviction_proposal
+        fund_conviction_pool => fund_conviction_pool
+        stake_conviction => stake_conviction
+        withdraw_conviction => withdraw_conviction
+        move_conviction => move_conviction
+        execute_conviction_proposal => execute_conviction_proposal
+        lock_tokens => lock_tokens
+        extend_lock => extend_lock
+        withdraw_lock => withdraw_lock
+        claim_voting_nfts => claim_voting_nfts
+        deposit_to_treasury => deposit_to_treasury
+        execute_treasury_spend => execute_treasury_spend
+        claim_stream => claim_stream
+        cancel_stream => cancel_stream
+        fund_voting_reward => fund_voting_reward
+        claim_voting_reward => claim_voting_reward
+        refund_voting_reward => refund_voting_reward
+        sweep_voting_reward => sweep_voting_reward
+        claim_creator_bounty => claim_creator_bounty
+        reclaim_creator_bounty => reclaim_creator_bounty
+        execute_code_upgrade => execute_code_upgrade
+        stake_prediction => stake_prediction
+        claim_prediction => claim_prediction
+        create_space => create_space
+        add_space_admin => add_space_admin
+        remove_space_admin => remove_space_admin
+        register_space_voters => register_space_voters
+        remove_space_voters => remove_space_voters
+        set_space_config => set_space_config
+        create_space_proposal => create_space_proposal
+        vote_in_space => vote_in_space
+        cancel_space_proposal => cancel_space_proposal
+        finalize_space_proposal => finalize_space_proposal
         get_proposal_title => get_proposal_title
         get_proposal_description => get_proposal_description
         get_proposal_creator => get_proposal_creator
@@ -31,10 +102,114 @@ multiversx_sc_wasm_adapter::endpoints! {
         get_proposal_deadline => get_proposal_deadline
         is_proposal_active => is_proposal_active
         get_total_proposals => get_total_proposals
+        get_default_proposal_count => get_default_proposal_count
         get_total_votes => get_total_votes
         has_user_voted_on_proposal => has_user_voted_on_proposal
         is_contract_paused => is_contract_paused
+        is_operation_paused => is_operation_paused
+        get_pause_state => get_pause_state
+        get_pausers => get_pausers
+        is_pauser => is_pauser
         get_owner => get_owner
+        get_proposal => get_proposal
+        get_proposal_status => get_proposal_status
+        get_proposals => get_proposals
+        get_proposals_by_status => get_proposals_by_status
+        get_active_proposal_count => get_active_proposal_count
+        get_proposal_options => get_proposal_options
+        get_option_vote_count => get_option_vote_count
+        get_proposal_voters => get_proposal_voters
+        get_proposal_voter_count => get_proposal_voter_count
+        get_proposal_document => get_proposal_document
+        get_proposal_amendments => get_proposal_amendments
+        get_amendment_parent => get_amendment_parent
+        get_proposal_outcome => get_proposal_outcome
+        get_finalization_bounty => get_finalization_bounty
+        get_finalization_pool => get_finalization_pool
+        get_anti_sniping_config => get_anti_sniping_config
+        get_proposal_extension_count => get_proposal_extension_count
+        get_ballot_type => get_ballot_type
+        get_score_range => get_score_range
+        get_proposal_seats => get_proposal_seats
+        get_elected_options => get_elected_options
+        get_ranked_rounds => get_ranked_rounds
+        get_ranked_count_state => get_ranked_count_state
+        get_governance_token => get_governance_token
+        get_conviction_config => get_conviction_config
+        get_conviction_pool => get_conviction_pool
+        get_conviction_total_staked => get_conviction_total_staked
+        get_conviction => get_conviction
+        get_conviction_threshold => get_conviction_threshold
+        get_conviction_staked => get_conviction_staked
+        get_conviction_stake => get_conviction_stake
+        get_vote_escrow_config => get_vote_escrow_config
+        get_vote_escrow_lock => get_vote_escrow_lock
+        get_voting_power => get_voting_power
+        get_voting_power_at => get_voting_power_at
+        get_unlock_time => get_unlock_time
+        get_vote_escrow_total_locked => get_vote_escrow_total_locked
+        get_proposal_voting_power => get_proposal_voting_power
+        get_nft_gate => get_nft_gate
+        is_nft_nonce_used => is_nft_nonce_used
+        get_nft_deposits => get_nft_deposits
+        get_proposal_snapshot => get_proposal_snapshot
+        get_treasury_balance => get_treasury_balance
+        get_treasury_balances => get_treasury_balances
+        get_governance_action_rules => get_governance_action_rules
+        get_treasury_spend => get_treasury_spend
+        is_treasury_spend_executed => is_treasury_spend_executed
+        get_spend_vesting => get_spend_vesting
+        get_stream_count => get_stream_count
+        get_stream => get_stream
+        get_stream_vested => get_stream_vested
+        get_stream_claimable => get_stream_claimable
+        get_streams_by_recipient => get_streams_by_recipient
+        get_voting_reward_pool => get_voting_reward_pool
+        get_voting_reward_distributed => get_voting_reward_distributed
+        get_voting_reward_deadline => get_voting_reward_deadline
+        get_voting_reward => get_voting_reward
+        has_claimed_voting_reward => has_claimed_voting_reward
+        get_voting_reward_contribution => get_voting_reward_contribution
+        get_default_bounty_rule => get_default_bounty_rule
+        get_creator_bounty => get_creator_bounty
+        get_creator_bounty_rule => get_creator_bounty_rule
+        get_creator_bounty_balance => get_creator_bounty_balance
+        get_creator_bounty_share => get_creator_bounty_share
+        has_claimed_creator_bounty => has_claimed_creator_bounty
+        get_prediction_fee => get_prediction_fee
+        get_prediction_market => get_prediction_market
+        get_prediction_pools => get_prediction_pools
+        get_prediction_total_staked => get_prediction_total_staked
+        get_prediction_position => get_prediction_position
+        get_prediction_winning_option => get_prediction_winning_option
+        get_prediction_payout => get_prediction_payout
+        get_code_upgrade => get_code_upgrade
+        get_code_upgrade_eta => get_code_upgrade_eta
+        is_code_upgrade_executed => is_code_upgrade_executed
+        get_storage_version => get_storage_version
+        get_migration_progress => get_migration_progress
+        get_categories => get_categories
+        get_category => get_category
+        get_proposals_by_category => get_proposals_by_category
+        get_category_proposal_count => get_category_proposal_count
+        get_proposal_tags => get_proposal_tags
+        get_space_count => get_space_count
+        get_space => get_space
+        get_space_admins => get_space_admins
+        is_space_admin => is_space_admin
+        is_space_voter => is_space_voter
+        get_space_voters => get_space_voters
+        get_space_config => get_space_config
+        get_space_total_proposals => get_space_total_proposals
+        get_space_total_votes => get_space_total_votes
+        get_space_proposal => get_space_proposal
+        get_space_proposals => get_space_proposals
+        get_space_proposal_id => get_space_proposal_id
+        get_proposal_space => get_proposal_space
+        get_proposals_by_creator => get_proposals_by_creator
+        get_creator_proposal_count => get_creator_proposal_count
+        get_votes_by_voter => get_votes_by_voter
+        get_voter_vote_count => get_voter_vote_count
     )
 }
 